@@ -16,12 +16,12 @@
 
 extern crate criterion;
 
-use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+use snarkvm_algorithms::fft::{with_parallel_threshold, DensePolynomial, EvaluationDomain};
 use snarkvm_curves::bls12_377::Fr as Bls12_377_Fr;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::TestRng;
 
-use criterion::{criterion_group, criterion_main, Bencher, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BatchSize, Bencher, BenchmarkId, Criterion};
 use std::cmp::min;
 
 /// Degree bounds to benchmark on
@@ -112,8 +112,84 @@ fn fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
     setup_bench(c, &description, bench_coset_ifft_in_place::<F>);
 }
 
+/// The number of multiplications performed in each iteration of the repeated multiplication benchmarks.
+const NUM_REPEATED_MULTIPLICATIONS: usize = 1000;
+
+fn repeated_mul_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
+    // Use the degree of the coinbase puzzle polynomials.
+    let degree = (1 << 13) - 1;
+    let rng = &mut TestRng::default();
+    let a = DensePolynomial::<F>::rand(degree, rng);
+    let b = DensePolynomial::<F>::rand(degree, rng);
+
+    let domain = EvaluationDomain::<F>::new(2 * degree + 1).unwrap();
+    let fft_pc = domain.precompute_fft();
+    let ifft_pc = domain.precompute_ifft();
+
+    let mut group =
+        c.benchmark_group(format!("{:?} - {} repeated multiplications", name, NUM_REPEATED_MULTIPLICATIONS));
+    group.sample_size(10);
+    group.bench_function("without_precomputation", |bencher| {
+        bencher.iter(|| {
+            for _ in 0..NUM_REPEATED_MULTIPLICATIONS {
+                let _product = &a * &b;
+            }
+        })
+    });
+    group.bench_function("with_precomputation", |bencher| {
+        bencher.iter(|| {
+            for _ in 0..NUM_REPEATED_MULTIPLICATIONS {
+                let _product = a.mul_with_pc(&b, &fft_pc, &ifft_pc).unwrap();
+            }
+        })
+    });
+    group.finish();
+}
+
+/// The number of inputs transformed in each iteration of the batched FFT benchmarks.
+const NUM_BATCHED_INPUTS: usize = 1000;
+
+fn batch_fft_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
+    // Use the degree of the coinbase puzzle polynomials.
+    let (domain, a) = create_evaluation_domain::<F>(1 << 13);
+    let inputs = vec![a; NUM_BATCHED_INPUTS];
+
+    let mut group = c.benchmark_group(format!("{:?} - {} batched iFFTs", name, NUM_BATCHED_INPUTS));
+    group.sample_size(10);
+    group.bench_function("individually", |bencher| {
+        bencher.iter_batched_ref(
+            || inputs.clone(),
+            |inputs| inputs.iter_mut().for_each(|input| domain.ifft_in_place(input)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("batched", |bencher| {
+        bencher.iter_batched_ref(|| inputs.clone(), |inputs| domain.ifft_batch(inputs), BatchSize::LargeInput)
+    });
+    group.finish();
+}
+
+/// Compares serial and parallel FFTs on small domains, to choose the default parallel threshold.
+fn parallel_threshold_benches<F: PrimeField>(c: &mut Criterion, name: &str) {
+    let mut group = c.benchmark_group(format!("{:?} - parallel threshold", name));
+    for log_size in 4..=10 {
+        let (domain, mut a) = create_evaluation_domain::<F>(1 << log_size);
+        for (description, threshold) in [("serial", u32::MAX), ("parallel", 0)] {
+            with_parallel_threshold(threshold, || {
+                group.bench_with_input(BenchmarkId::new(description, 1 << log_size), &log_size, |b, _| {
+                    b.iter(|| domain.fft_in_place(&mut a))
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
 fn bench_bls12_377(c: &mut Criterion) {
     fft_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
+    repeated_mul_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
+    batch_fft_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
+    parallel_threshold_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
 }
 
 criterion_group!(benches, bench_bls12_377);
@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+
+use snarkvm_algorithms::{
+    fft::{DensePolynomial, EvaluationDomain},
+    polycommit::kzg10::{KZGCommitment, LagrangeBasis, Powers, KZG10},
+};
+use snarkvm_curves::bls12_377::Bls12_377;
+use snarkvm_utilities::TestRng;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::Rng;
+use std::{borrow::Cow, sync::atomic::AtomicBool};
+
+/// The sizes of the domains to benchmark on, from 2^{12} to 2^{15}.
+const BENCHMARK_LOG_SIZES: [usize; 4] = [12, 13, 14, 15];
+
+fn commit_evaluations(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+    let max_size = 1 << BENCHMARK_LOG_SIZES[BENCHMARK_LOG_SIZES.len() - 1];
+    let pp = KZG10::<Bls12_377>::load_srs(max_size).unwrap();
+
+    let mut group = c.benchmark_group("KZG10 commitments to evaluations");
+    for log_size in BENCHMARK_LOG_SIZES {
+        let size = 1 << log_size;
+        let domain = EvaluationDomain::new(size).unwrap();
+        let powers = Powers::<Bls12_377> {
+            powers_of_beta_g: Cow::Owned(pp.powers_of_beta_g(0, size).unwrap()),
+            powers_of_beta_times_gamma_g: Cow::Owned(vec![]),
+        };
+        let lagrange_basis = LagrangeBasis::<Bls12_377> {
+            lagrange_basis_at_beta_g: Cow::Owned(pp.lagrange_basis(domain).unwrap()),
+            powers_of_beta_times_gamma_g: Cow::Owned(vec![]),
+            domain,
+        };
+        let evaluations = DensePolynomial::rand(size - 1, rng).evaluate_over_domain(domain);
+
+        // Interpolate the evaluations with an iFFT, and commit to the coefficients.
+        group.bench_with_input(BenchmarkId::new("interpolate_and_commit", size), &evaluations, |b, evaluations| {
+            b.iter(|| {
+                let polynomial = evaluations.interpolate_by_ref();
+                KZG10::commit(&powers, &(&polynomial).into(), None, &AtomicBool::new(false), None).unwrap()
+            })
+        });
+        // Commit to the evaluations directly, with the Lagrange basis.
+        group.bench_with_input(BenchmarkId::new("commit_evaluations", size), &evaluations, |b, evaluations| {
+            b.iter(|| {
+                KZG10::commit_evaluations(&lagrange_basis, evaluations, None, &AtomicBool::new(false), None).unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn batch_validate(c: &mut Criterion) {
+    let rng = &mut TestRng::default();
+
+    let mut group = c.benchmark_group("KZG10 commitment validation");
+    for num_commitments in [1_000, 10_000] {
+        let commitments = (0..num_commitments).map(|_| KZGCommitment::<Bls12_377>(rng.gen())).collect::<Vec<_>>();
+
+        // Check the commitments one by one, on a single thread.
+        group.bench_with_input(BenchmarkId::new("per_commitment", num_commitments), &commitments, |b, commitments| {
+            b.iter(|| {
+                assert!(commitments.iter().all(|commitment| commitment.is_in_correct_subgroup_assuming_on_curve()))
+            })
+        });
+        // Check the commitments in a single batch.
+        group.bench_with_input(BenchmarkId::new("batch_validate", num_commitments), &commitments, |b, commitments| {
+            b.iter(|| KZGCommitment::batch_validate(commitments).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = kzg10;
+    config = Criterion::default().sample_size(10);
+    targets = commit_evaluations, batch_validate
+}
+
+criterion_main!(kzg10);
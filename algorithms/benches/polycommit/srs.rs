@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+extern crate criterion;
+
+use snarkvm_algorithms::polycommit::kzg10::UniversalParams;
+use snarkvm_curves::bls12_377::{Bls12_377, G1Affine};
+use snarkvm_utilities::{
+    deserialize_affine_vec,
+    read_affine_vec,
+    read_vec_exact,
+    CanonicalDeserialize,
+    CanonicalSerialize,
+    Compress,
+    TestRng,
+    ToBytes,
+    Uniform,
+    Validate,
+};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// The number of points to benchmark on, from 2^{15} to 2^{18}.
+const BENCHMARK_LOG_NUM_POINTS: [usize; 4] = [15, 16, 17, 18];
+
+fn srs_load(c: &mut Criterion) {
+    c.bench_function("SRS load", |b| b.iter(|| UniversalParams::<Bls12_377>::load().unwrap()));
+}
+
+fn affine_vec_reads(c: &mut Criterion) {
+    let mut rng = TestRng::default();
+    let max_num_points = 1 << BENCHMARK_LOG_NUM_POINTS[BENCHMARK_LOG_NUM_POINTS.len() - 1];
+    let points = (0..max_num_points).map(|_| G1Affine::rand(&mut rng)).collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("Affine vector reads");
+    for log_num_points in BENCHMARK_LOG_NUM_POINTS {
+        let num_points = 1 << log_num_points;
+        let points = &points[..num_points];
+
+        // Benchmark the `ToBytes` encoding, as used by proving keys.
+        let bytes = points.iter().flat_map(|point| point.to_bytes_le().unwrap()).collect::<Vec<_>>();
+        group.bench_with_input(BenchmarkId::new("read_vec_exact", num_points), &bytes, |b, bytes| {
+            b.iter(|| read_vec_exact::<G1Affine, _>(&bytes[..], num_points).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("read_affine_vec", num_points), &bytes, |b, bytes| {
+            b.iter(|| read_affine_vec::<G1Affine, _>(&bytes[..], num_points, Validate::No).unwrap())
+        });
+
+        // Benchmark the uncompressed canonical encoding, as used by the SRS.
+        let mut bytes = vec![];
+        points.serialize_uncompressed(&mut bytes).unwrap();
+        group.bench_with_input(BenchmarkId::new("Vec::deserialize", num_points), &bytes, |b, bytes| {
+            b.iter(|| Vec::<G1Affine>::deserialize_uncompressed_unchecked(&bytes[..]).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("deserialize_affine_vec", num_points), &bytes, |b, bytes| {
+            b.iter(|| {
+                deserialize_affine_vec::<G1Affine, _>(&bytes[8..], num_points, Compress::No, Validate::No).unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = srs;
+    config = Criterion::default().sample_size(10);
+    targets = srs_load, affine_vec_reads
+}
+
+criterion_main!(srs);
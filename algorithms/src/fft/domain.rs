@@ -31,12 +31,18 @@ use crate::{
     cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
-    fft::{DomainCoeff, SparsePolynomial},
+    fft::{
+        parallel::{execute_with_threshold, is_parallel, mul_assign_pointwise},
+        DomainCoeff,
+        DomainError,
+        MixedRadixEvaluationDomain,
+        SparsePolynomial,
+    },
 };
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 #[cfg(feature = "parallel")]
 use snarkvm_utilities::max_available_threads;
-use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
+use snarkvm_utilities::serialize::*;
 
 use rand::Rng;
 use std::{borrow::Cow, fmt};
@@ -114,37 +120,58 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Construct a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
+    ///
+    /// Returns `None` if the field does not support a domain of this size; use `try_new` for the reason.
     pub fn new(num_coeffs: usize) -> Option<Self> {
+        Self::try_new(num_coeffs).ok()
+    }
+
+    /// Construct a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients, or return an error describing why the field does not support it.
+    pub fn try_new(num_coeffs: usize) -> Result<Self, DomainError> {
+        let too_large = || DomainError::SizeTooLarge { num_coeffs, two_adicity: F::FftParameters::TWO_ADICITY };
+
         // Compute the size of our evaluation domain
-        let size = num_coeffs.next_power_of_two() as u64;
+        let size = num_coeffs.checked_next_power_of_two().ok_or_else(too_large)? as u64;
         let log_size_of_group = size.trailing_zeros();
 
         // libfqfft uses > https://github.com/scipr-lab/libfqfft/blob/e0183b2cef7d4c5deb21a6eaf3fe3b586d738fe0/libfqfft/evaluation_domain/domains/basic_radix2_domain.tcc#L33
         if log_size_of_group > F::FftParameters::TWO_ADICITY {
-            return None;
+            return Err(too_large());
         }
 
         // Compute the generator for the multiplicative subgroup.
         // It should be the 2^(log_size_of_group) root of unity.
-        let group_gen = F::get_root_of_unity(size as usize)?;
+        let group_gen = F::get_root_of_unity(size as usize).ok_or(DomainError::MissingRootOfUnity { size })?;
 
         // Check that it is indeed the 2^(log_size_of_group) root of unity.
         debug_assert_eq!(group_gen.pow([size]), F::one());
 
         let size_as_field_element = F::from(size);
-        let size_inv = size_as_field_element.inverse()?;
+        // The inverses exist, as the size is a power of two less than the characteristic,
+        // and the generators are non-zero.
+        let size_inv = size_as_field_element.inverse().unwrap();
 
-        Some(EvaluationDomain {
+        Ok(EvaluationDomain {
             size,
             log_size_of_group,
             size_as_field_element,
             size_inv,
             group_gen,
-            group_gen_inv: group_gen.inverse()?,
-            generator_inv: F::multiplicative_generator().inverse()?,
+            group_gen_inv: group_gen.inverse().unwrap(),
+            generator_inv: F::multiplicative_generator().inverse().unwrap(),
         })
     }
 
+    /// Construct the smallest mixed-radix domain that is large enough for evaluations
+    /// of a polynomial having `num_coeffs` coefficients.
+    ///
+    /// The size of the returned domain is of the form `2^i * q^j`, where `q` is the small subgroup
+    /// base of the field, and is never larger than the size of `EvaluationDomain::new(num_coeffs)`.
+    pub fn new_mixed(num_coeffs: usize) -> Option<MixedRadixEvaluationDomain<F>> {
+        MixedRadixEvaluationDomain::new(num_coeffs)
+    }
+
     /// Return the size of a domain that is large enough for evaluations of a polynomial
     /// having `num_coeffs` coefficients.
     pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
@@ -171,7 +198,7 @@ impl<F: FftField> EvaluationDomain<F> {
             snarkvm_cuda::NTT_batch(self.size(), 1, coeffs,
                                     snarkvm_cuda::NTTInputOutputOrder::NN);
         } else {
-            execute_with_max_available_threads(|| {
+            execute_with_threshold(self.size(), || {
                 coeffs.resize(self.size(), T::zero());
                 self.in_order_fft_in_place(&mut *coeffs);
             });
@@ -193,13 +220,69 @@ impl<F: FftField> EvaluationDomain<F> {
             snarkvm_cuda::iNTT_batch(self.size(), 1, evals,
                                      snarkvm_cuda::NTTInputOutputOrder::NN);
         } else {
-            execute_with_max_available_threads(|| {
+            execute_with_threshold(self.size(), || {
                 evals.resize(self.size(), T::zero());
                 self.in_order_ifft_in_place(&mut *evals);
             });
         }
     }
 
+    /// Compute the FFTs of many inputs in place, sharing the roots of unity between them.
+    /// On the GPU, the inputs are transformed by a single batched NTT.
+    /// Otherwise, the transforms are parallelized across the inputs, rather than within each transform.
+    pub fn fft_batch<T: DomainCoeff<F>>(&self, inputs: &mut [Vec<T>]) {
+        if self.log_size_of_group > 10 {
+            self.ntt_batch_in_place(inputs, snarkvm_cuda::NTT_batch::<T>);
+            return;
+        }
+        execute_with_threshold(self.size() * inputs.len(), || {
+            let pc = self.precompute_fft();
+            cfg_iter_mut!(inputs).for_each(|input| {
+                input.resize(self.size(), T::zero());
+                self.fft_helper_in_place_with_pc(input, FFTOrder::II, &pc);
+            });
+        });
+    }
+
+    /// Compute the IFFTs of many inputs in place, sharing the inverse roots of unity between them.
+    /// On the GPU, the inputs are transformed by a single batched inverse NTT.
+    /// Otherwise, the transforms are parallelized across the inputs, rather than within each transform.
+    pub fn ifft_batch<T: DomainCoeff<F>>(&self, inputs: &mut [Vec<T>]) {
+        if self.log_size_of_group > 10 {
+            self.ntt_batch_in_place(inputs, snarkvm_cuda::iNTT_batch::<T>);
+            return;
+        }
+        execute_with_threshold(self.size() * inputs.len(), || {
+            let pc = self.precompute_ifft();
+            cfg_iter_mut!(inputs).for_each(|input| {
+                input.resize(self.size(), T::zero());
+                self.ifft_helper_in_place_with_pc(input, FFTOrder::II, &pc);
+                Self::scale_in_place(input, self.size_inv);
+            });
+        });
+    }
+
+    /// Transforms the inputs in place with one call to the given batched NTT,
+    /// by laying out the inputs, padded to the size of the domain, in a single buffer.
+    fn ntt_batch_in_place<T: DomainCoeff<F>>(
+        &self,
+        inputs: &mut [Vec<T>],
+        ntt_batch: fn(usize, usize, &mut [T], snarkvm_cuda::NTTInputOutputOrder),
+    ) {
+        if inputs.is_empty() {
+            return;
+        }
+        let mut buffer = Vec::with_capacity(self.size() * inputs.len());
+        for input in inputs.iter_mut() {
+            input.resize(self.size(), T::zero());
+            buffer.extend_from_slice(input);
+        }
+        ntt_batch(self.size(), inputs.len(), &mut buffer, snarkvm_cuda::NTTInputOutputOrder::NN);
+        for (input, output) in inputs.iter_mut().zip(buffer.chunks_exact(self.size())) {
+            input.copy_from_slice(output);
+        }
+    }
+
     /// Compute an FFT over a coset of the domain.
     pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
         let mut coeffs = coeffs.to_vec();
@@ -210,8 +293,20 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an FFT over a coset of the domain, modifying the input vector
     /// in place.
     pub fn coset_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
-            Self::distribute_powers(coeffs, F::multiplicative_generator());
+        self.coset_fft_in_place_with_offset(coeffs, F::multiplicative_generator());
+    }
+
+    /// Compute an FFT over the coset `offset * H` of the domain `H`.
+    pub fn coset_fft_with_offset<T: DomainCoeff<F>>(&self, coeffs: &[T], offset: F) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.coset_fft_in_place_with_offset(&mut coeffs, offset);
+        coeffs
+    }
+
+    /// Compute an FFT over the coset `offset * H` of the domain `H`, modifying the input vector in place.
+    pub fn coset_fft_in_place_with_offset<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>, offset: F) {
+        execute_with_threshold(self.size(), || {
+            Self::distribute_powers(coeffs, offset);
             self.fft_in_place(coeffs);
         });
     }
@@ -225,20 +320,37 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
     pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
-        execute_with_max_available_threads(|| {
+        execute_with_threshold(self.size(), || {
             evals.resize(self.size(), T::zero());
             self.in_order_coset_ifft_in_place(&mut *evals);
         });
     }
 
+    /// Compute an IFFT over the coset `offset * H` of the domain `H`.
+    pub fn coset_ifft_with_offset<T: DomainCoeff<F>>(&self, evals: &[T], offset: F) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.coset_ifft_in_place_with_offset(&mut evals, offset);
+        evals
+    }
+
+    /// Compute an IFFT over the coset `offset * H` of the domain `H`, modifying the input vector in place.
+    ///
+    /// Panics if `offset` is zero.
+    pub fn coset_ifft_in_place_with_offset<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>, offset: F) {
+        let offset_inv = offset.inverse().expect("the coset offset must be non-zero");
+        execute_with_threshold(self.size(), || {
+            self.ifft_in_place(evals);
+            Self::distribute_powers(evals, offset_inv);
+        });
+    }
+
     /// Multiply the `i`-th element of `coeffs` with `g^i`.
     fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
         Self::distribute_powers_and_mul_by_const(coeffs, g, F::one());
     }
 
     /// Multiply the `i`-th element of `coeffs` with `c*g^i`.
-    #[cfg(not(feature = "parallel"))]
-    fn distribute_powers_and_mul_by_const<T: DomainCoeff<F>>(coeffs: &mut [T], g: F, c: F) {
+    fn distribute_powers_and_mul_by_const_serial<T: DomainCoeff<F>>(coeffs: &mut [T], g: F, c: F) {
         // invariant: pow = c*g^i at the ith iteration of the loop
         let mut pow = c;
         coeffs.iter_mut().for_each(|coeff| {
@@ -247,9 +359,18 @@ impl<F: FftField> EvaluationDomain<F> {
         })
     }
 
+    /// Multiply the `i`-th element of `coeffs` with `c*g^i`.
+    #[cfg(not(feature = "parallel"))]
+    fn distribute_powers_and_mul_by_const<T: DomainCoeff<F>>(coeffs: &mut [T], g: F, c: F) {
+        Self::distribute_powers_and_mul_by_const_serial(coeffs, g, c)
+    }
+
     /// Multiply the `i`-th element of `coeffs` with `c*g^i`.
     #[cfg(feature = "parallel")]
     fn distribute_powers_and_mul_by_const<T: DomainCoeff<F>>(coeffs: &mut [T], g: F, c: F) {
+        if !is_parallel(coeffs.len()) {
+            return Self::distribute_powers_and_mul_by_const_serial(coeffs, g, c);
+        }
         let min_parallel_chunk_size = 1024;
         let num_cpus_available = max_available_threads();
         let num_elem_per_thread = core::cmp::max(coeffs.len() / num_cpus_available, min_parallel_chunk_size);
@@ -264,6 +385,14 @@ impl<F: FftField> EvaluationDomain<F> {
         });
     }
 
+    /// Multiply each element of `x_s` with `c`.
+    fn scale_in_place<T: DomainCoeff<F>>(x_s: &mut [T], c: F) {
+        match is_parallel(x_s.len()) {
+            true => cfg_iter_mut!(x_s).for_each(|val| *val *= c),
+            false => x_s.iter_mut().for_each(|val| *val *= c),
+        }
+    }
+
     /// Evaluate all the lagrange polynomials defined by this domain at the point
     /// `tau`.
     pub fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
@@ -314,9 +443,40 @@ impl<F: FftField> EvaluationDomain<F> {
         tau.pow(&[self.size]) - F::one()
     }
 
+    /// Returns the evaluations of the vanishing polynomial of this domain
+    /// over the coset `offset * D` of the domain `D = coset_domain`.
+    ///
+    /// These are non-zero whenever `offset` lies outside of this domain, so that polynomials
+    /// which vanish on this domain can be divided by the vanishing polynomial pointwise over the coset.
+    pub fn evaluate_vanishing_polynomial_over_coset(&self, coset_domain: &Self, offset: F) -> Vec<F> {
+        // As both domains have power-of-two size, `(offset * g^i)^n` is periodic in `i`,
+        // with a period of `|D| / n` if `n` divides `|D|`, and of one otherwise.
+        let period = core::cmp::max(coset_domain.size() / self.size(), 1);
+        let mut evals = compute_powers_and_mul_by_const_serial(
+            period,
+            coset_domain.group_gen.pow([self.size]),
+            offset.pow([self.size]),
+        );
+        evals.iter_mut().for_each(|eval| *eval -= F::one());
+        evals.into_iter().cycle().take(coset_domain.size()).collect()
+    }
+
     /// Return an iterator over the elements of the domain.
     pub fn elements(&self) -> Elements<F> {
-        Elements { cur_elem: F::one(), cur_pow: 0, domain: *self }
+        Elements::new(*self, 0, self.size)
+    }
+
+    /// Return a parallel iterator over the elements of the domain, in order.
+    /// Each thread computes the powers of the generator for a contiguous chunk of the domain.
+    #[cfg(feature = "parallel")]
+    pub fn par_elements(&self) -> impl ParallelIterator<Item = F> {
+        let domain = *self;
+        let chunk_size = core::cmp::max(self.size() / max_available_threads(), MIN_PARALLEL_CHUNK_SIZE) as u64;
+        let num_chunks = (self.size + chunk_size - 1) / chunk_size;
+        (0..num_chunks).into_par_iter().flat_map_iter(move |i| {
+            let start = i * chunk_size;
+            Elements::new(domain, start, core::cmp::min(start + chunk_size, domain.size))
+        })
     }
 
     /// The target polynomial is the zero polynomial in our
@@ -359,9 +519,10 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Returns the evaluations of the product over the domain.
     #[must_use]
     pub fn mul_polynomials_in_evaluation_domain(&self, self_evals: &[F], other_evals: &[F]) -> Vec<F> {
+        assert_eq!(self_evals.len(), other_evals.len());
         let mut result = self_evals.to_vec();
 
-        cfg_iter_mut!(result).zip_eq(other_evals).for_each(|(a, b)| *a *= b);
+        mul_assign_pointwise(&mut result, other_evals);
 
         result
     }
@@ -369,14 +530,14 @@ impl<F: FftField> EvaluationDomain<F> {
 
 impl<F: FftField> EvaluationDomain<F> {
     pub fn precompute_fft(&self) -> FFTPrecomputation<F> {
-        execute_with_max_available_threads(|| FFTPrecomputation {
+        execute_with_threshold(self.size(), || FFTPrecomputation {
             roots: self.roots_of_unity(self.group_gen),
             domain: *self,
         })
     }
 
     pub fn precompute_ifft(&self) -> IFFTPrecomputation<F> {
-        execute_with_max_available_threads(|| IFFTPrecomputation {
+        execute_with_threshold(self.size(), || IFFTPrecomputation {
             inverse_roots: self.roots_of_unity(self.group_gen_inv),
             domain: *self,
         })
@@ -399,7 +560,7 @@ impl<F: FftField> EvaluationDomain<F> {
     pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         let pc = self.precompute_ifft();
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, &pc);
-        cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
+        Self::scale_in_place(x_s, self.size_inv);
     }
 
     pub(crate) fn in_order_coset_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
@@ -418,6 +579,28 @@ impl<F: FftField> EvaluationDomain<F> {
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp)
     }
 
+    /// Computes an in-order FFT by deranging the input and performing an FFT with out-of-order input.
+    /// Unlike `in_order_fft_in_place_with_pc`, this does not copy the roots of unity in `pre_comp`.
+    pub(crate) fn deranged_fft_in_place_with_pc<T: DomainCoeff<F>>(
+        &self,
+        x_s: &mut [T],
+        pre_comp: &FFTPrecomputation<F>,
+    ) {
+        derange(x_s);
+        self.fft_helper_in_place_with_pc(x_s, FFTOrder::OI, pre_comp)
+    }
+
+    /// Computes an in-order IFFT by deranging the input and performing an IFFT with out-of-order input.
+    /// This is the inverse of `deranged_fft_in_place_with_pc`.
+    pub(crate) fn deranged_ifft_in_place_with_pc<T: DomainCoeff<F>>(
+        &self,
+        x_s: &mut [T],
+        pre_comp: &IFFTPrecomputation<F>,
+    ) {
+        derange(x_s);
+        self.out_order_ifft_in_place_with_pc(x_s, pre_comp)
+    }
+
     pub(crate) fn out_order_fft_in_place_with_pc<T: DomainCoeff<F>>(
         &self,
         x_s: &mut [T],
@@ -441,7 +624,7 @@ impl<F: FftField> EvaluationDomain<F> {
         pre_comp: &IFFTPrecomputation<F>,
     ) {
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::OI, pre_comp);
-        cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
+        Self::scale_in_place(x_s, self.size_inv);
     }
 
     #[allow(unused)]
@@ -516,7 +699,7 @@ impl<F: FftField> EvaluationDomain<F> {
         // TODO: check if this method can replace parallel compute powers.
         let log_size = log2(self.size as usize);
         // early exit for short inputs
-        if log_size <= LOG_ROOTS_OF_UNITY_PARALLEL_SIZE {
+        if log_size <= LOG_ROOTS_OF_UNITY_PARALLEL_SIZE || !is_parallel(self.size()) {
             compute_powers_serial((self.size as usize) / 2, root)
         } else {
             let mut temp = root;
@@ -595,6 +778,14 @@ impl<F: FftField> EvaluationDomain<F> {
         max_threads: usize,
         gap: usize,
     ) {
+        if !is_parallel(xi.len()) {
+            xi.chunks_mut(chunk_size).for_each(|cxi| {
+                let (lo, hi) = cxi.split_at_mut(gap);
+                lo.iter_mut().zip(hi).zip(roots.iter().step_by(step)).for_each(g);
+            });
+            return;
+        }
+
         cfg_chunks_mut!(xi, chunk_size).for_each(|cxi| {
             let (lo, hi) = cxi.split_at_mut(gap);
             // If the chunk is sufficiently big that parallelism helps,
@@ -630,7 +821,10 @@ impl<F: FftField> EvaluationDomain<F> {
             // Which also implies a large lookup stride.
             if num_chunks >= MIN_NUM_CHUNKS_FOR_COMPACTION {
                 if !first {
-                    roots = Cow::Owned(cfg_into_iter!(roots.into_owned()).step_by(step * 2).collect());
+                    roots = Cow::Owned(match is_parallel(xi.len()) {
+                        true => cfg_into_iter!(roots.into_owned()).step_by(step * 2).collect(),
+                        false => roots.iter().step_by(step * 2).copied().collect(),
+                    });
                 }
                 step = 1;
                 roots.to_mut().shrink_to_fit();
@@ -678,9 +872,15 @@ impl<F: FftField> EvaluationDomain<F> {
             // the roots lookup is done a significant amount of times
             // Which also implies a large lookup stride.
             let (roots, step) = if num_chunks >= MIN_NUM_CHUNKS_FOR_COMPACTION && gap < xi.len() / 2 {
-                cfg_iter_mut!(compacted_roots[..gap])
-                    .zip(cfg_iter!(roots_cache[..(gap * num_chunks)]).step_by(num_chunks))
-                    .for_each(|(a, b)| *a = *b);
+                match is_parallel(xi.len()) {
+                    true => cfg_iter_mut!(compacted_roots[..gap])
+                        .zip(cfg_iter!(roots_cache[..(gap * num_chunks)]).step_by(num_chunks))
+                        .for_each(|(a, b)| *a = *b),
+                    false => compacted_roots[..gap]
+                        .iter_mut()
+                        .zip(roots_cache[..(gap * num_chunks)].iter().step_by(num_chunks))
+                        .for_each(|(a, b)| *a = *b),
+                }
                 (&compacted_roots[..gap], 1)
             } else {
                 (roots_cache, num_chunks)
@@ -776,18 +976,37 @@ pub(crate) fn compute_powers<F: Field>(size: usize, g: F) -> Vec<F> {
 }
 
 /// An iterator over the elements of the domain.
+/// The elements are computed on the fly, as successive powers of the generator.
 #[derive(Clone)]
 pub struct Elements<F: FftField> {
+    /// The next element from the front, `g^cur_pow`.
     cur_elem: F,
     cur_pow: u64,
+    /// The element after the next element from the back, `g^end_pow`.
+    end_elem: F,
+    end_pow: u64,
     domain: EvaluationDomain<F>,
 }
 
+impl<F: FftField> Elements<F> {
+    /// Returns an iterator over the elements `g^start, ..., g^(end - 1)` of the domain.
+    fn new(domain: EvaluationDomain<F>, start: u64, end: u64) -> Self {
+        debug_assert!(start <= end && end <= domain.size);
+        Self {
+            cur_elem: domain.group_gen.pow([start]),
+            cur_pow: start,
+            end_elem: domain.group_gen.pow([end]),
+            end_pow: end,
+            domain,
+        }
+    }
+}
+
 impl<F: FftField> Iterator for Elements<F> {
     type Item = F;
 
     fn next(&mut self) -> Option<F> {
-        if self.cur_pow == self.domain.size {
+        if self.cur_pow == self.end_pow {
             None
         } else {
             let cur_elem = self.cur_elem;
@@ -796,8 +1015,41 @@ impl<F: FftField> Iterator for Elements<F> {
             Some(cur_elem)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end_pow - self.cur_pow) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<F> {
+        // Skip ahead with a single exponentiation, rather than `n` multiplications.
+        if (n as u64) < self.end_pow - self.cur_pow {
+            self.cur_elem *= self.domain.group_gen.pow([n as u64]);
+            self.cur_pow += n as u64;
+            self.next()
+        } else {
+            self.cur_pow = self.end_pow;
+            None
+        }
+    }
 }
 
+impl<F: FftField> DoubleEndedIterator for Elements<F> {
+    fn next_back(&mut self) -> Option<F> {
+        if self.cur_pow == self.end_pow {
+            None
+        } else {
+            self.end_elem *= &self.domain.group_gen_inv;
+            self.end_pow -= 1;
+            Some(self.end_elem)
+        }
+    }
+}
+
+impl<F: FftField> ExactSizeIterator for Elements<F> {}
+
+impl<F: FftField> core::iter::FusedIterator for Elements<F> {}
+
 /// An iterator over the elements of the domain.
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub struct FFTPrecomputation<F: FftField> {
@@ -854,12 +1106,15 @@ impl<F: FftField> IFFTPrecomputation<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::fft::{DensePolynomial, EvaluationDomain};
+    use super::compute_powers_serial;
+    use crate::fft::{DensePolynomial, DomainError, EvaluationDomain, Evaluations};
     use snarkvm_curves::bls12_377::Fr;
-    use snarkvm_fields::{FftField, Field, One, Zero};
+    use snarkvm_fields::{FftField, FftParameters, Field, One, Zero};
     use snarkvm_utilities::Uniform;
 
     use rand::{thread_rng, Rng};
+    #[cfg(feature = "parallel")]
+    use rayon::prelude::*;
 
     #[test]
     fn vanishing_polynomial_evaluation() {
@@ -906,6 +1161,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn elements_iterator() {
+        for log_size in 0..12 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let expected = compute_powers_serial(domain.size(), domain.group_gen);
+
+            // The elements have an exact size, and can be iterated from either end.
+            let mut elements = domain.elements();
+            assert_eq!(elements.len(), domain.size());
+            assert_eq!(elements.clone().collect::<Vec<_>>(), expected);
+            assert_eq!(elements.clone().rev().collect::<Vec<_>>(), expected.iter().rev().copied().collect::<Vec<_>>());
+            if domain.size() > 2 {
+                assert_eq!(elements.next(), Some(expected[0]));
+                assert_eq!(elements.next_back(), Some(expected[domain.size() - 1]));
+                assert_eq!(elements.len(), domain.size() - 2);
+                assert_eq!(elements.collect::<Vec<_>>(), expected[1..domain.size() - 1]);
+            }
+
+            // Skipping ahead matches iterating one element at a time.
+            for n in [0, 1, domain.size() / 2, domain.size() - 1, domain.size()] {
+                assert_eq!(domain.elements().nth(n), expected.get(n).copied());
+                let mut elements = domain.elements();
+                elements.nth(n);
+                assert_eq!(elements.collect::<Vec<_>>(), expected.get(n + 1..).unwrap_or_default());
+            }
+
+            #[cfg(feature = "parallel")]
+            assert_eq!(domain.par_elements().collect::<Vec<_>>(), expected);
+        }
+    }
+
     /// Test that lagrange interpolation for a random polynomial at a random point works.
     #[test]
     fn non_systematic_lagrange_coefficients_test() {
@@ -1016,6 +1302,109 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fft_batch() {
+        let rng = &mut thread_rng();
+        // Domains of size above 2^10 use the batched NTT.
+        for log_size in [0, 1, 5, 10, 11] {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            for num_inputs in [0, 1, 7] {
+                // Inputs shorter than the domain are padded with zeros.
+                let inputs = (0..num_inputs)
+                    .map(|i| DensePolynomial::<Fr>::rand(domain.size() - 1 - i % domain.size(), rng).coeffs)
+                    .collect::<Vec<_>>();
+
+                let mut evaluations = inputs.clone();
+                domain.fft_batch(&mut evaluations);
+                assert_eq!(evaluations, inputs.iter().map(|input| domain.fft(input)).collect::<Vec<_>>());
+
+                let mut coefficients = evaluations.clone();
+                domain.ifft_batch(&mut coefficients);
+                assert_eq!(coefficients, evaluations.iter().map(|evals| domain.ifft(evals)).collect::<Vec<_>>());
+                for (coefficients, input) in coefficients.iter().zip(&inputs) {
+                    assert_eq!(coefficients[..input.len()], input[..]);
+                    assert!(coefficients[input.len()..].iter().all(|c| c.is_zero()));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_domain_too_large() {
+        let two_adicity = <Fr as FftField>::FftParameters::TWO_ADICITY;
+
+        // The largest supported domain can be described, even if it is too large to use here.
+        assert!(EvaluationDomain::<Fr>::try_new(1 << two_adicity).is_ok());
+
+        for num_coeffs in [(1 << two_adicity) + 1, 1 << 60, usize::MAX] {
+            let error = EvaluationDomain::<Fr>::try_new(num_coeffs).unwrap_err();
+            assert_eq!(error, DomainError::SizeTooLarge { num_coeffs, two_adicity });
+            assert_eq!(
+                error.to_string(),
+                format!("Cannot construct an evaluation domain for {num_coeffs} coefficients; ")
+                    + &format!("the maximum size is 2^{two_adicity}")
+            );
+            assert!(EvaluationDomain::<Fr>::new(num_coeffs).is_none());
+        }
+    }
+
+    /// Tests that the coset FFTs round-trip for an arbitrary coset offset.
+    #[test]
+    fn test_coset_fft_with_offset() {
+        let rng = &mut thread_rng();
+        for log_domain_size in 0..10 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let polynomial = DensePolynomial::<Fr>::rand(domain.size() - 1, rng);
+            let offset = domain.sample_element_outside_domain(rng);
+
+            let evaluations = domain.coset_fft_with_offset(&polynomial.coeffs, offset);
+            for (evaluation, x) in evaluations.iter().zip(domain.elements()) {
+                assert_eq!(*evaluation, polynomial.evaluate(offset * x));
+            }
+            let coeffs = domain.coset_ifft_with_offset(&evaluations, offset);
+            assert_eq!(DensePolynomial::from_coefficients_vec(coeffs), polynomial);
+        }
+    }
+
+    /// Tests that the vanishing polynomial is correctly evaluated over cosets of smaller and larger domains.
+    #[test]
+    fn test_evaluate_vanishing_polynomial_over_coset() {
+        let rng = &mut thread_rng();
+        for log_domain_size in 0..6 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            for log_coset_domain_size in 0..8 {
+                let coset_domain = EvaluationDomain::<Fr>::new(1 << log_coset_domain_size).unwrap();
+                let offset = Fr::rand(rng);
+                let expected = coset_domain
+                    .elements()
+                    .map(|x| domain.evaluate_vanishing_polynomial(offset * x))
+                    .collect::<Vec<_>>();
+                assert_eq!(domain.evaluate_vanishing_polynomial_over_coset(&coset_domain, offset), expected);
+            }
+        }
+    }
+
+    /// Tests that dividing by the vanishing polynomial over a coset recovers the quotient.
+    #[test]
+    fn test_divide_on_coset() {
+        let rng = &mut thread_rng();
+        for log_domain_size in 0..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let coset_domain = EvaluationDomain::<Fr>::new(2 * domain.size()).unwrap();
+            let quotient = DensePolynomial::<Fr>::rand(domain.size() - 1, rng);
+            let dividend = quotient.mul_by_vanishing_poly(domain);
+
+            let offset = Fr::multiplicative_generator();
+            let evaluations = Evaluations::from_vec_and_domain(
+                coset_domain.coset_fft_with_offset(&dividend.coeffs, offset),
+                coset_domain,
+            );
+            let evaluations = evaluations.divide_on_coset(domain, offset);
+            let coeffs = coset_domain.coset_ifft_with_offset(&evaluations.evaluations, offset);
+            assert_eq!(DensePolynomial::from_coefficients_vec(coeffs), quotient);
+        }
+    }
+
     /// Tests that FFT precomputation is correctly subdomained
     #[test]
     fn test_fft_precomputation() {
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use thiserror::Error;
+
+/// The error type for constructing an `EvaluationDomain`.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum DomainError {
+    /// The requested domain is larger than the two-adic subgroup of the field.
+    #[error("Cannot construct an evaluation domain for {num_coeffs} coefficients; the maximum size is 2^{two_adicity}")]
+    SizeTooLarge {
+        /// The number of coefficients the domain was requested for.
+        num_coeffs: usize,
+        /// The two-adicity of the field, i.e. the log of the maximum supported domain size.
+        two_adicity: u32,
+    },
+    /// The field does not provide a root of unity generating a subgroup of the requested size.
+    #[error("Cannot construct an evaluation domain of size {size}; the field has no root of unity of that order")]
+    MissingRootOfUnity {
+        /// The size of the domain, a power of two.
+        size: u64,
+    },
+}
+
+/// The error type for interpolating a polynomial through a set of points.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum InterpolationError {
+    /// The number of values does not match the number of points.
+    #[error("Cannot interpolate {num_values} values through {num_points} points")]
+    LengthMismatch {
+        /// The number of points to interpolate through.
+        num_points: usize,
+        /// The number of values to interpolate.
+        num_values: usize,
+    },
+    /// The points are not distinct.
+    #[error("Cannot interpolate through repeated points")]
+    DuplicatePoints,
+}
+
+/// The error type for arithmetic on `Evaluations`.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum EvaluationsError {
+    /// The operands are evaluated over domains of different sizes.
+    #[error("Cannot combine evaluations over a domain of size {lhs} with evaluations over a domain of size {rhs}")]
+    DomainMismatch {
+        /// The size of the domain of the left operand.
+        lhs: u64,
+        /// The size of the domain of the right operand.
+        rhs: u64,
+    },
+    /// The operands have different numbers of evaluations.
+    #[error("Cannot combine {lhs} evaluations with {rhs} evaluations")]
+    LengthMismatch {
+        /// The number of evaluations of the left operand.
+        lhs: usize,
+        /// The number of evaluations of the right operand.
+        rhs: usize,
+    },
+    /// The target domain is smaller than the domain of the evaluations.
+    #[error("Cannot extend evaluations over a domain of size {size} to a domain of size {target}")]
+    DomainTooSmall {
+        /// The size of the domain of the evaluations.
+        size: u64,
+        /// The size of the target domain.
+        target: u64,
+    },
+}
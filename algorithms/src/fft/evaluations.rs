@@ -16,14 +16,21 @@
 
 //! A polynomial represented in evaluations form.
 
-use crate::fft::{DensePolynomial, EvaluationDomain};
+use crate::fft::{
+    polynomial::{read_field_elements, write_field_elements, MAX_NUM_SERIALIZED_ELEMENTS},
+    DensePolynomial,
+    EvaluationDomain,
+    EvaluationsError,
+};
 #[cfg(not(feature = "parallel"))]
 use itertools::Itertools;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use snarkvm_fields::PrimeField;
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, serialize::*};
+use snarkvm_fields::{batch_inversion, PrimeField};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, error, serialize::*, SerializeExt};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
@@ -44,7 +51,7 @@ impl<F: PrimeField> Evaluations<F> {
         Self { evaluations, domain }
     }
 
-    /// Interpolate a polynomial from a list of evaluations
+    /// Interpolate a polynomial from a list of evaluations, without consuming `self`.
     pub fn interpolate_by_ref(&self) -> DensePolynomial<F> {
         DensePolynomial::from_coefficients_vec(self.domain.ifft(&self.evaluations))
     }
@@ -89,6 +96,127 @@ impl<F: PrimeField> Evaluations<F> {
     pub fn evaluate_with_coeffs(&self, lagrange_coefficients_at_point: &[F]) -> F {
         cfg_iter!(self.evaluations).zip_eq(lagrange_coefficients_at_point).map(|(a, b)| *a * b).sum()
     }
+
+    /// Given the evaluations of a polynomial over the coset `offset * D` of the domain `D` of `self`,
+    /// returns the evaluations over the same coset of its quotient by the vanishing polynomial of `vanishing_domain`.
+    ///
+    /// The result only interpolates to a polynomial if the vanishing polynomial divides the original polynomial.
+    /// Panics if the coset intersects `vanishing_domain`.
+    pub fn divide_on_coset(mut self, vanishing_domain: EvaluationDomain<F>, offset: F) -> Self {
+        let mut inverses = vanishing_domain.evaluate_vanishing_polynomial_over_coset(&self.domain, offset);
+        assert!(!inverses.iter().any(|eval| eval.is_zero()), "the coset intersects the vanishing domain");
+        batch_inversion(&mut inverses);
+        cfg_iter_mut!(self.evaluations).zip(&inverses).for_each(|(eval, inverse)| *eval *= inverse);
+        self
+    }
+
+    /// Given the evaluations of a polynomial over the coset `offset * D` of the domain `D` of `self`,
+    /// returns the evaluations over the same coset of its product with the vanishing polynomial of `vanishing_domain`.
+    ///
+    /// The result only interpolates to the product if `D` is large enough to contain its coefficients.
+    pub fn mul_by_vanishing_poly_on_coset(mut self, vanishing_domain: EvaluationDomain<F>, offset: F) -> Self {
+        let vanishing_evals = vanishing_domain.evaluate_vanishing_polynomial_over_coset(&self.domain, offset);
+        cfg_iter_mut!(self.evaluations).zip(&vanishing_evals).for_each(|(eval, vanishing)| *eval *= vanishing);
+        self
+    }
+
+    /// Returns the evaluations of the same polynomial over `domain`, which must be at least as large as
+    /// the domain of `self`. The polynomial is interpolated with an iFFT, and re-evaluated with an FFT.
+    pub fn extend_to(&self, domain: EvaluationDomain<F>) -> Result<Self, EvaluationsError> {
+        if domain.size < self.domain.size {
+            return Err(EvaluationsError::DomainTooSmall { size: self.domain.size, target: domain.size });
+        }
+        let mut evaluations = self.domain.ifft(&self.evaluations);
+        domain.fft_in_place(&mut evaluations);
+        Ok(Self::from_vec_and_domain(evaluations, domain))
+    }
+
+    /// Multiplies each evaluation of `self` by the corresponding evaluation of `other`.
+    pub fn try_mul_assign(&mut self, other: &Self) -> Result<(), EvaluationsError> {
+        self.check_compatible(other)?;
+        cfg_iter_mut!(self.evaluations).zip(&other.evaluations).for_each(|(a, b)| *a *= b);
+        Ok(())
+    }
+
+    /// Adds each evaluation of `other` to the corresponding evaluation of `self`.
+    pub fn try_add_assign(&mut self, other: &Self) -> Result<(), EvaluationsError> {
+        self.check_compatible(other)?;
+        cfg_iter_mut!(self.evaluations).zip(&other.evaluations).for_each(|(a, b)| *a += b);
+        Ok(())
+    }
+
+    /// Subtracts each evaluation of `other` from the corresponding evaluation of `self`.
+    pub fn try_sub_assign(&mut self, other: &Self) -> Result<(), EvaluationsError> {
+        self.check_compatible(other)?;
+        cfg_iter_mut!(self.evaluations).zip(&other.evaluations).for_each(|(a, b)| *a -= b);
+        Ok(())
+    }
+
+    /// Divides each evaluation of `self` by the corresponding evaluation of `other`.
+    pub fn try_div_assign(&mut self, other: &Self) -> Result<(), EvaluationsError> {
+        self.check_compatible(other)?;
+        cfg_iter_mut!(self.evaluations).zip(&other.evaluations).for_each(|(a, b)| *a /= b);
+        Ok(())
+    }
+
+    /// Ensures that `self` and `other` are evaluations over the same domain.
+    fn check_compatible(&self, other: &Self) -> Result<(), EvaluationsError> {
+        if self.domain != other.domain {
+            Err(EvaluationsError::DomainMismatch { lhs: self.domain.size, rhs: other.domain.size })
+        } else if self.evaluations.len() != other.evaluations.len() {
+            Err(EvaluationsError::LengthMismatch { lhs: self.evaluations.len(), rhs: other.evaluations.len() })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<F: PrimeField> Evaluations<F> {
+    /// Reads evaluations over a domain of size at most `max_domain_size` from a buffer.
+    /// There must be exactly one evaluation for each element of the domain.
+    pub fn read_le_with_max_domain_size<R: Read>(mut reader: R, max_domain_size: u64) -> io::Result<Self> {
+        let domain_size: u64 = FromBytes::read_le(&mut reader)?;
+        if domain_size > max_domain_size {
+            return Err(error("the domain size exceeds the maximum"));
+        }
+        let domain = EvaluationDomain::new(domain_size as usize)
+            .filter(|domain| domain.size == domain_size)
+            .ok_or_else(|| error("invalid evaluation domain size"))?;
+        let evaluations = read_field_elements(&mut reader, domain_size)?;
+        if evaluations.len() as u64 != domain_size {
+            return Err(error("the number of evaluations does not match the domain size"));
+        }
+        Ok(Self { evaluations, domain })
+    }
+}
+
+impl<F: PrimeField> FromBytes for Evaluations<F> {
+    /// Reads evaluations over a domain of size at most `MAX_NUM_SERIALIZED_ELEMENTS` from a buffer.
+    fn read_le<R: Read>(reader: R) -> io::Result<Self> {
+        Self::read_le_with_max_domain_size(reader, MAX_NUM_SERIALIZED_ELEMENTS)
+    }
+}
+
+impl<F: PrimeField> ToBytes for Evaluations<F> {
+    /// Writes the domain size and the evaluations to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.domain.size.write_le(&mut writer)?;
+        write_field_elements(&self.evaluations, writer)
+    }
+}
+
+impl<F: PrimeField> Serialize for Evaluations<F> {
+    /// Serializes the evaluations to a hex string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize_hex_or_bytes(serializer)
+    }
+}
+
+impl<'de, F: PrimeField> Deserialize<'de> for Evaluations<F> {
+    /// Deserializes the evaluations from a hex string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::deserialize_hex_or_bytes(deserializer)
+    }
 }
 
 impl<F: PrimeField> std::ops::Index<usize> for Evaluations<F> {
@@ -113,8 +241,9 @@ impl<'a, 'b, F: PrimeField> Mul<&'a Evaluations<F>> for &'b Evaluations<F> {
 impl<'a, F: PrimeField> MulAssign<&'a Evaluations<F>> for Evaluations<F> {
     #[inline]
     fn mul_assign(&mut self, other: &'a Evaluations<F>) {
-        assert_eq!(self.domain, other.domain, "domains are unequal");
-        cfg_iter_mut!(self.evaluations).zip_eq(&other.evaluations).for_each(|(a, b)| *a *= b);
+        if let Err(error) = self.try_mul_assign(other) {
+            panic!("{error}");
+        }
     }
 }
 
@@ -132,8 +261,9 @@ impl<'a, 'b, F: PrimeField> Add<&'a Evaluations<F>> for &'b Evaluations<F> {
 impl<'a, F: PrimeField> AddAssign<&'a Evaluations<F>> for Evaluations<F> {
     #[inline]
     fn add_assign(&mut self, other: &'a Evaluations<F>) {
-        assert_eq!(self.domain, other.domain, "domains are unequal");
-        cfg_iter_mut!(self.evaluations).zip_eq(&other.evaluations).for_each(|(a, b)| *a += b);
+        if let Err(error) = self.try_add_assign(other) {
+            panic!("{error}");
+        }
     }
 }
 
@@ -151,8 +281,9 @@ impl<'a, 'b, F: PrimeField> Sub<&'a Evaluations<F>> for &'b Evaluations<F> {
 impl<'a, F: PrimeField> SubAssign<&'a Evaluations<F>> for Evaluations<F> {
     #[inline]
     fn sub_assign(&mut self, other: &'a Evaluations<F>) {
-        assert_eq!(self.domain, other.domain, "domains are unequal");
-        cfg_iter_mut!(self.evaluations).zip_eq(&other.evaluations).for_each(|(a, b)| *a -= b);
+        if let Err(error) = self.try_sub_assign(other) {
+            panic!("{error}");
+        }
     }
 }
 
@@ -170,7 +301,181 @@ impl<'a, 'b, F: PrimeField> Div<&'a Evaluations<F>> for &'b Evaluations<F> {
 impl<'a, F: PrimeField> DivAssign<&'a Evaluations<F>> for Evaluations<F> {
     #[inline]
     fn div_assign(&mut self, other: &'a Evaluations<F>) {
-        assert_eq!(self.domain, other.domain, "domains are unequal");
-        cfg_iter_mut!(self.evaluations).zip_eq(&other.evaluations).for_each(|(a, b)| *a /= b);
+        if let Err(error) = self.try_div_assign(other) {
+            panic!("{error}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::FftField;
+    use snarkvm_utilities::{assert_serde_consistency, rand::Uniform};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut thread_rng();
+        for log_domain_size in 0..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let evaluations = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+            let expected = Evaluations::from_vec_and_domain(evaluations, domain);
+
+            let bytes = expected.to_bytes_le().unwrap();
+            let candidate = Evaluations::read_le(&bytes[..]).unwrap();
+            assert_eq!(candidate.domain(), domain);
+            assert_eq!(candidate, expected);
+
+            // Truncated inputs are rejected.
+            assert!(Evaluations::<Fr>::read_le(&bytes[..bytes.len() - 1]).is_err());
+            // The maximum domain size is enforced.
+            assert!(Evaluations::<Fr>::read_le_with_max_domain_size(&bytes[..], domain.size / 2).is_err());
+        }
+    }
+
+    #[test]
+    fn test_invalid_domain_size() {
+        for domain_size in [0u64, 3, (1 << 10) + 1, MAX_NUM_SERIALIZED_ELEMENTS * 2, u64::MAX] {
+            let mut bytes = domain_size.to_bytes_le().unwrap();
+            bytes.extend(0u64.to_bytes_le().unwrap());
+            assert!(Evaluations::<Fr>::read_le(&bytes[..]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_wrong_number_of_evaluations() {
+        let rng = &mut thread_rng();
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        for num_evaluations in [0, 1, domain.size() / 2, domain.size() - 1] {
+            let mut bytes = domain.size.to_bytes_le().unwrap();
+            bytes.extend((num_evaluations as u64).to_bytes_le().unwrap());
+            (0..num_evaluations).for_each(|_| bytes.extend(Fr::rand(rng).to_bytes_le().unwrap()));
+            // Every element of the domain must have an evaluation.
+            assert!(Evaluations::<Fr>::read_le(&bytes[..]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        let rng = &mut thread_rng();
+        for log_domain_size in 0..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let evaluations = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+            assert_serde_consistency!(Evaluations<Fr>, Evaluations::from_vec_and_domain(evaluations, domain));
+        }
+    }
+
+    #[test]
+    fn test_bytes_length_bomb() {
+        let evaluation = Fr::rand(&mut thread_rng());
+        for num_evaluations in [MAX_NUM_SERIALIZED_ELEMENTS, u64::MAX] {
+            let mut bytes = MAX_NUM_SERIALIZED_ELEMENTS.to_bytes_le().unwrap();
+            bytes.extend(num_evaluations.to_bytes_le().unwrap());
+            bytes.extend(evaluation.to_bytes_le().unwrap());
+            // The length prefixes are not trusted, so this fails without allocating the claimed length.
+            assert!(Evaluations::<Fr>::read_le(&bytes[..]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_matches_coefficient_form() {
+        let rng = &mut thread_rng();
+        for log_domain_size in 1..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let a = DensePolynomial::<Fr>::rand(domain.size() / 2 - 1, rng);
+            let b = DensePolynomial::<Fr>::rand(domain.size() / 2 - 1, rng);
+            let a_evals = a.evaluate_over_domain_by_ref(domain);
+            let b_evals = b.evaluate_over_domain_by_ref(domain);
+
+            let mut product = a_evals.clone();
+            product.try_mul_assign(&b_evals).unwrap();
+            assert_eq!(product.interpolate_by_ref(), &a * &b);
+            assert_eq!(product, &a_evals * &b_evals);
+
+            let mut sum = a_evals.clone();
+            sum.try_add_assign(&b_evals).unwrap();
+            assert_eq!(sum.interpolate_by_ref(), &a + &b);
+            assert_eq!(sum, &a_evals + &b_evals);
+
+            let mut difference = a_evals.clone();
+            difference.try_sub_assign(&b_evals).unwrap();
+            assert_eq!(difference.interpolate_by_ref(), &a - &b);
+            assert_eq!(difference, &a_evals - &b_evals);
+
+            let mut quotient = product.clone();
+            quotient.try_div_assign(&b_evals).unwrap();
+            assert_eq!(quotient, a_evals);
+            assert_eq!(quotient, &product / &b_evals);
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_mismatch() {
+        let rng = &mut thread_rng();
+        let small = EvaluationDomain::<Fr>::new(4).unwrap();
+        let large = EvaluationDomain::<Fr>::new(8).unwrap();
+        let a = DensePolynomial::<Fr>::rand(3, rng).evaluate_over_domain(small);
+        let b = DensePolynomial::<Fr>::rand(3, rng).evaluate_over_domain(large);
+
+        let mut c = a.clone();
+        let expected = EvaluationsError::DomainMismatch { lhs: 4, rhs: 8 };
+        assert_eq!(c.try_mul_assign(&b), Err(expected.clone()));
+        assert_eq!(c.try_add_assign(&b), Err(expected.clone()));
+        assert_eq!(c.try_sub_assign(&b), Err(expected.clone()));
+        assert_eq!(c.try_div_assign(&b), Err(expected));
+        assert_eq!(c, a);
+
+        let truncated = Evaluations::from_vec_and_domain(a.evaluations[..3].to_vec(), small);
+        assert_eq!(c.try_add_assign(&truncated), Err(EvaluationsError::LengthMismatch { lhs: 4, rhs: 3 }));
+    }
+
+    #[test]
+    fn test_mul_by_vanishing_poly_on_coset() {
+        let rng = &mut thread_rng();
+        let offset = Fr::multiplicative_generator();
+        for log_size in 0..5 {
+            let vanishing_domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            for degree in [0, 1, vanishing_domain.size() - 1, vanishing_domain.size(), 3 * vanishing_domain.size()] {
+                let polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+                let expected = polynomial.mul_by_vanishing_poly(vanishing_domain);
+
+                let coset_domain = EvaluationDomain::<Fr>::new(degree + vanishing_domain.size() + 1).unwrap();
+                let evaluations = coset_domain.coset_fft_with_offset(&polynomial.coeffs, offset);
+                let product = Evaluations::from_vec_and_domain(evaluations, coset_domain)
+                    .mul_by_vanishing_poly_on_coset(vanishing_domain, offset);
+                let coeffs = coset_domain.coset_ifft_with_offset(&product.evaluations, offset);
+                assert_eq!(DensePolynomial::from_coefficients_vec(coeffs), expected);
+
+                // Dividing on the coset recovers the original polynomial.
+                let quotient = product.divide_on_coset(vanishing_domain, offset);
+                let coeffs = coset_domain.coset_ifft_with_offset(&quotient.evaluations, offset);
+                assert_eq!(DensePolynomial::from_coefficients_vec(coeffs), polynomial);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extend_to() {
+        let rng = &mut thread_rng();
+        for log_domain_size in 0u32..6 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_domain_size).unwrap();
+            let polynomial = DensePolynomial::<Fr>::rand(domain.size() - 1, rng);
+            let evaluations = polynomial.evaluate_over_domain_by_ref(domain);
+            for log_target_size in log_domain_size..8 {
+                let target = EvaluationDomain::<Fr>::new(1 << log_target_size).unwrap();
+                let extended = evaluations.extend_to(target).unwrap();
+                assert_eq!(extended, polynomial.evaluate_over_domain_by_ref(target));
+                assert_eq!(extended.interpolate_by_ref(), polynomial);
+            }
+            if let Some(smaller) = log_domain_size.checked_sub(1).and_then(|log| EvaluationDomain::new(1 << log)) {
+                assert_eq!(
+                    evaluations.extend_to(smaller),
+                    Err(EvaluationsError::DomainTooSmall { size: domain.size, target: smaller.size })
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,442 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! This module contains a `MixedRadixEvaluationDomain`, a multiplicative subgroup
+//! of size `2^i * q^j`, where `q` is the small subgroup base of the scalar field.
+//!
+//! When the natural size of a polynomial is just above a power of two, rounding up
+//! to the next power of two can double the FFT work. A mixed-radix domain instead
+//! rounds up to the next size of the form `2^i * q^j`, which can be much smaller.
+
+use crate::{
+    cfg_iter_mut,
+    fft::{DomainCoeff, EvaluationDomain, SparsePolynomial},
+};
+use snarkvm_fields::{FftField, FftParameters};
+
+use std::fmt;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Defines a domain over which finite field (I)FFTs can be performed. The domain
+/// is a multiplicative subgroup of size `2^two_adicity * q^q_adicity`.
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+pub struct MixedRadixEvaluationDomain<F: FftField> {
+    /// The size of the domain.
+    pub size: u64,
+    /// The power of two in the size of the domain.
+    pub two_adicity: u32,
+    /// The power of the small subgroup base in the size of the domain.
+    pub q_adicity: u32,
+    /// Size of the domain as a field element.
+    pub size_as_field_element: F,
+    /// Inverse of the size in the field.
+    pub size_inv: F,
+    /// A generator of the subgroup.
+    pub group_gen: F,
+    /// Inverse of the generator of the subgroup.
+    pub group_gen_inv: F,
+}
+
+impl<F: FftField> fmt::Debug for MixedRadixEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mixed-radix multiplicative subgroup of size {}", self.size)
+    }
+}
+
+impl<F: FftField> MixedRadixEvaluationDomain<F> {
+    /// Construct the smallest mixed-radix domain that is large enough for evaluations
+    /// of a polynomial having `num_coeffs` coefficients.
+    ///
+    /// If the field does not define a small subgroup, or if no mixed-radix size is smaller,
+    /// this returns a domain of the same size as `EvaluationDomain::new(num_coeffs)`.
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        let (two_adicity, q_adicity) = Self::compute_adicities(num_coeffs)?;
+        let q = F::FftParameters::SMALL_SUBGROUP_BASE.unwrap_or(1) as u64;
+        let size = (1u64 << two_adicity).checked_mul(q.checked_pow(q_adicity)?)?;
+
+        // Compute the generator for the multiplicative subgroup.
+        let group_gen = F::get_root_of_unity(size as usize)?;
+        // Check that it is indeed a root of unity of order `size`.
+        debug_assert_eq!(group_gen.pow([size]), F::one());
+
+        let size_as_field_element = F::from(size);
+        let size_inv = size_as_field_element.inverse()?;
+
+        Some(Self {
+            size,
+            two_adicity,
+            q_adicity,
+            size_as_field_element,
+            size_inv,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+        })
+    }
+
+    /// Returns the powers of two and of the small subgroup base in the size of the smallest domain
+    /// that is large enough for evaluations of a polynomial having `num_coeffs` coefficients.
+    fn compute_adicities(num_coeffs: usize) -> Option<(u32, u32)> {
+        let num_coeffs = num_coeffs.max(1) as u64;
+        let (q, max_q_adicity) = match (
+            F::FftParameters::SMALL_SUBGROUP_BASE,
+            F::FftParameters::SMALL_SUBGROUP_BASE_ADICITY,
+            F::large_subgroup_root_of_unity(),
+        ) {
+            (Some(q), Some(q_adicity), Some(_)) => (q as u64, q_adicity),
+            _ => (1, 0),
+        };
+
+        let mut best: Option<(u64, u32, u32)> = None;
+        let mut q_part = 1u64;
+        for q_adicity in 0..=max_q_adicity {
+            // Round the remaining factor up to the next power of two.
+            let two_part = ((num_coeffs + q_part - 1) / q_part).checked_next_power_of_two()?;
+            let two_adicity = two_part.trailing_zeros();
+            if two_adicity <= F::FftParameters::TWO_ADICITY {
+                if let Some(size) = two_part.checked_mul(q_part) {
+                    if best.map_or(true, |(best_size, ..)| size < best_size) {
+                        best = Some((size, two_adicity, q_adicity));
+                    }
+                }
+            }
+            q_part = match q_part.checked_mul(q) {
+                Some(q_part) => q_part,
+                None => break,
+            };
+        }
+        best.map(|(_, two_adicity, q_adicity)| (two_adicity, q_adicity))
+    }
+
+    /// Return the size of a domain that is large enough for evaluations of a polynomial
+    /// having `num_coeffs` coefficients.
+    pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        Self::new(num_coeffs).map(|domain| domain.size())
+    }
+
+    /// Return the size of `self`.
+    pub fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    /// Returns `true` if `self` has the same size as the power-of-two `EvaluationDomain`,
+    /// in which case there is no benefit to using the mixed-radix domain.
+    pub fn is_radix_2(&self) -> bool {
+        self.q_adicity == 0
+    }
+
+    /// Returns the power-of-two `EvaluationDomain` of the same size, if `self` is a radix-2 domain.
+    pub fn to_radix_2_domain(&self) -> Option<EvaluationDomain<F>> {
+        match self.is_radix_2() {
+            true => EvaluationDomain::new(self.size()),
+            false => None,
+        }
+    }
+
+    /// Compute an FFT.
+    pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
+        let mut coeffs = coeffs.to_vec();
+        self.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// Compute an FFT, modifying the vector in place.
+    pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        assert!(coeffs.len() <= self.size(), "the number of coefficients exceeds the domain size");
+        coeffs.resize(self.size(), T::zero());
+        self.mixed_radix_fft_in_place(coeffs, self.group_gen);
+    }
+
+    /// Compute an IFFT.
+    pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
+        let mut evals = evals.to_vec();
+        self.ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// Compute an IFFT, modifying the vector in place.
+    pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        assert!(evals.len() <= self.size(), "the number of evaluations exceeds the domain size");
+        evals.resize(self.size(), T::zero());
+        self.mixed_radix_fft_in_place(evals, self.group_gen_inv);
+        cfg_iter_mut!(evals).for_each(|val| *val *= self.size_inv);
+    }
+
+    /// Return the sparse vanishing polynomial.
+    pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        let coeffs = [(0, -F::one()), (self.size(), F::one())];
+        SparsePolynomial::from_coefficients(coeffs)
+    }
+
+    /// This evaluates the vanishing polynomial for this domain at tau.
+    /// For multiplicative subgroups, this polynomial is `z(X) = X^self.size - 1`.
+    pub fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
+        tau.pow([self.size]) - F::one()
+    }
+
+    /// Return an iterator over the elements of the domain.
+    pub fn elements(&self) -> MixedRadixElements<F> {
+        MixedRadixElements { cur_elem: F::one(), cur_pow: 0, size: self.size, group_gen: self.group_gen }
+    }
+
+    /// Perform O(n) multiplication of two polynomials that are presented by their
+    /// evaluations in the domain.
+    /// Returns the evaluations of the product over the domain.
+    pub fn mul_polynomials_in_evaluation_domain(&self, self_evals: &[F], other_evals: &[F]) -> Vec<F> {
+        assert_eq!(self_evals.len(), other_evals.len());
+        let mut result = self_evals.to_vec();
+        cfg_iter_mut!(result).zip(other_evals).for_each(|(a, b)| *a *= b);
+        result
+    }
+
+    /// Performs a serial, in-place mixed-radix FFT over the subgroup generated by `omega`.
+    ///
+    /// Conceptually, the input is first split into 2 sub-arrays `two_adicity` times,
+    /// and then into `q` sub-arrays `q_adicity` times. The merge passes run in the opposite order.
+    fn mixed_radix_fft_in_place<T: DomainCoeff<F>>(&self, a: &mut [T], omega: F) {
+        let n = a.len();
+        debug_assert_eq!(n as u64, self.size);
+        let q = F::FftParameters::SMALL_SUBGROUP_BASE.unwrap_or(1) as usize;
+
+        // Apply the index permutation. Unlike the bit-reversal permutation, it is not an involution,
+        // so we follow each cycle, tracking which elements have already been moved.
+        let mut seen = vec![false; n];
+        for k in 0..n {
+            let mut i = k;
+            let mut a_i = a[i];
+            while !seen[i] {
+                let dest = self.permute(q, i);
+                let a_dest = a[dest];
+                a[dest] = a_i;
+                seen[i] = true;
+                a_i = a_dest;
+                i = dest;
+            }
+        }
+
+        let mut m = 1;
+
+        // Perform the radix-`q` merge passes.
+        if self.q_adicity > 0 {
+            let omega_q = omega.pow([(n / q) as u64]);
+            let mut qth_roots = Vec::with_capacity(q);
+            qth_roots.push(F::one());
+            for i in 1..q {
+                qth_roots.push(qth_roots[i - 1] * omega_q);
+            }
+
+            let mut terms = vec![T::zero(); q - 1];
+            for _ in 0..self.q_adicity {
+                let w_m = omega.pow([(n / (q * m)) as u64]);
+                let mut k = 0;
+                while k < n {
+                    // `w_j` is `w_m^j`.
+                    let mut w_j = F::one();
+                    for j in 0..m {
+                        let base_term = a[k + j];
+                        let mut w_j_i = w_j;
+                        for (i, term) in terms.iter_mut().enumerate() {
+                            *term = a[k + j + (i + 1) * m];
+                            *term *= w_j_i;
+                            w_j_i *= w_j;
+                        }
+                        for i in 0..q {
+                            a[k + j + i * m] = base_term;
+                            for (l, term) in terms.iter().enumerate() {
+                                let mut tmp = *term;
+                                tmp *= qth_roots[(i * (l + 1)) % q];
+                                a[k + j + i * m] += tmp;
+                            }
+                        }
+                        w_j *= w_m;
+                    }
+                    k += q * m;
+                }
+                m *= q;
+            }
+        }
+
+        // Perform the radix-2 merge passes.
+        for _ in 0..self.two_adicity {
+            let w_m = omega.pow([(n / (2 * m)) as u64]);
+            let mut k = 0;
+            while k < n {
+                let mut w = F::one();
+                for j in 0..m {
+                    let mut t = a[(k + m) + j];
+                    t *= w;
+                    a[(k + m) + j] = a[k + j];
+                    a[(k + m) + j] -= t;
+                    a[k + j] += t;
+                    w *= w_m;
+                }
+                k += 2 * m;
+            }
+            m *= 2;
+        }
+    }
+
+    /// Returns the position of the `i`-th input after splitting into 2 sub-arrays `two_adicity` times,
+    /// and then into `q` sub-arrays `q_adicity` times.
+    ///
+    /// Writing `i = b_0 + 2 b_1 + ... + 2^{s-1} b_{s-1} + 2^s (x_0 + q x_1 + ... + q^{t-1} x_{t-1})`,
+    /// this returns `b_0 (n / 2) + ... + b_{s-1} (n / 2^s) + x_0 (n / 2^s q) + ... + x_{t-1} (n / 2^s q^t)`.
+    fn permute(&self, q: usize, mut i: usize) -> usize {
+        let mut result = 0;
+        let mut shift = self.size();
+        for _ in 0..self.two_adicity {
+            shift /= 2;
+            result += (i % 2) * shift;
+            i /= 2;
+        }
+        for _ in 0..self.q_adicity {
+            shift /= q;
+            result += (i % q) * shift;
+            i /= q;
+        }
+        result
+    }
+}
+
+/// An iterator over the elements of a mixed-radix domain.
+#[derive(Clone)]
+pub struct MixedRadixElements<F: FftField> {
+    cur_elem: F,
+    cur_pow: u64,
+    size: u64,
+    group_gen: F,
+}
+
+impl<F: FftField> Iterator for MixedRadixElements<F> {
+    type Item = F;
+
+    fn next(&mut self) -> Option<F> {
+        if self.cur_pow == self.size {
+            None
+        } else {
+            let cur_elem = self.cur_elem;
+            self.cur_elem *= &self.group_gen;
+            self.cur_pow += 1;
+            Some(cur_elem)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::DensePolynomial;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{Field, One, Zero};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    #[test]
+    fn test_domain_size() {
+        // Sizes just above a power of two use the small subgroup.
+        let domain = MixedRadixEvaluationDomain::<Fr>::new((1 << 15) + 1).unwrap();
+        assert_eq!(domain.size(), 3 << 14);
+        assert!(!domain.is_radix_2());
+        assert!(domain.to_radix_2_domain().is_none());
+
+        // Powers of two stay powers of two.
+        for log_size in 0..10 {
+            let domain = MixedRadixEvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            assert_eq!(domain.size(), 1 << log_size);
+            assert!(domain.is_radix_2());
+            assert_eq!(domain.group_gen, domain.to_radix_2_domain().unwrap().group_gen);
+        }
+
+        // The domain is never larger than the radix-2 domain.
+        for num_coeffs in 0..1000 {
+            let mixed = MixedRadixEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            let radix_2 = EvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            assert!(mixed.size() >= num_coeffs);
+            assert!(mixed.size() <= radix_2.size());
+        }
+    }
+
+    #[test]
+    fn test_elements_and_vanishing_polynomial() {
+        let rng = &mut TestRng::default();
+        for num_coeffs in [1, 3, 5, 7, 12, 13, 48, 100] {
+            let domain = MixedRadixEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            let elements = domain.elements().collect::<Vec<_>>();
+            assert_eq!(elements.len(), domain.size());
+            for (i, element) in elements.iter().enumerate() {
+                assert_eq!(*element, domain.group_gen.pow([i as u64]));
+                assert!(domain.evaluate_vanishing_polynomial(*element).is_zero());
+            }
+            // The elements are distinct.
+            assert!(elements[1..].iter().all(|element| !element.is_one()));
+
+            let z = domain.vanishing_polynomial();
+            for _ in 0..10 {
+                let point = Fr::rand(rng);
+                assert_eq!(z.evaluate(point), domain.evaluate_vanishing_polynomial(point));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft_correctness() {
+        let rng = &mut TestRng::default();
+        for num_coeffs in [1, 2, 3, 6, 11, 24, 97, 192, (1 << 9) + 1] {
+            let domain = MixedRadixEvaluationDomain::<Fr>::new(num_coeffs).unwrap();
+            let polynomial = DensePolynomial::<Fr>::rand(num_coeffs - 1, rng);
+
+            // Check the FFT against direct evaluation.
+            let evaluations = domain.fft(&polynomial.coeffs);
+            for (evaluation, element) in evaluations.iter().zip(domain.elements()) {
+                assert_eq!(*evaluation, polynomial.evaluate(element));
+            }
+
+            // Check that the IFFT inverts the FFT.
+            let recovered = DensePolynomial::from_coefficients_vec(domain.ifft(&evaluations));
+            assert_eq!(recovered, polynomial);
+        }
+    }
+
+    #[test]
+    fn test_fft_matches_radix_2() {
+        let rng = &mut TestRng::default();
+        for log_size in 0..10 {
+            let mixed = MixedRadixEvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let radix_2 = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let polynomial = DensePolynomial::<Fr>::rand((1 << log_size) - 1, rng);
+            assert_eq!(mixed.fft(&polynomial.coeffs), radix_2.fft(&polynomial.coeffs));
+
+            let evaluations = (0..1 << log_size).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            assert_eq!(mixed.ifft(&evaluations), radix_2.ifft(&evaluations));
+        }
+    }
+
+    #[test]
+    fn test_polynomial_multiplication() {
+        let rng = &mut TestRng::default();
+        for degree in [1, 5, 16, 100, 1 << 8] {
+            let a = DensePolynomial::<Fr>::rand(degree, rng);
+            let b = DensePolynomial::<Fr>::rand(degree, rng);
+
+            let domain = MixedRadixEvaluationDomain::<Fr>::new(2 * degree + 1).unwrap();
+            let product_evaluations =
+                domain.mul_polynomials_in_evaluation_domain(&domain.fft(&a.coeffs), &domain.fft(&b.coeffs));
+            let product = DensePolynomial::from_coefficients_vec(domain.ifft(&product_evaluations));
+
+            assert_eq!(product, &a * &b);
+        }
+    }
+}
@@ -20,9 +20,23 @@
 pub mod domain;
 pub use domain::EvaluationDomain;
 
+pub mod errors;
+pub use errors::*;
+
 pub mod evaluations;
 pub use evaluations::Evaluations;
 
+pub mod mixed_radix_domain;
+pub use mixed_radix_domain::MixedRadixEvaluationDomain;
+
+pub mod parallel;
+pub use parallel::{
+    parallel_threshold,
+    set_parallel_threshold,
+    with_parallel_threshold,
+    DEFAULT_LOG_PARALLEL_THRESHOLD,
+};
+
 pub mod polynomial;
 pub use polynomial::{DensePolynomial, Polynomial, SparsePolynomial};
 
@@ -0,0 +1,233 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The threshold below which FFTs and polynomial arithmetic run serially, even under the `parallel` feature.
+
+use snarkvm_fields::Field;
+use snarkvm_utilities::{cfg_iter_mut, execute_with_max_available_threads};
+
+use core::{
+    cell::Cell,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The default value of `parallel_threshold`.
+///
+/// Below `2^10` elements, no butterfly of an FFT is split across threads, and the powers of the
+/// domain generator fit in a single chunk, so dispatching to the thread pool only adds overhead.
+///
+/// The `parallel threshold` group of the `fft` benchmark measures this overhead. On a single core,
+/// where dispatching cannot speed anything up, it costs about 70µs per FFT of any size: more than
+/// a serial FFT of `2^8` elements takes (68µs), half the time of one of `2^9` elements (134µs),
+/// and under 5% of the time of one of `2^10` elements (384µs). Multi-core speedups should be
+/// measured with the same benchmark before lowering the threshold.
+pub const DEFAULT_LOG_PARALLEL_THRESHOLD: u32 = 10;
+
+/// The base-2 logarithm of the number of elements below which operations run serially.
+static LOG_PARALLEL_THRESHOLD: AtomicU32 = AtomicU32::new(DEFAULT_LOG_PARALLEL_THRESHOLD);
+
+/// Sets the threshold below which FFTs, polynomial multiplication, and polynomial evaluation
+/// run serially, to `2^log2_size` elements. The results of these operations do not depend on it.
+///
+/// Setting the threshold to `0` parallelizes every operation, while setting it to `u32::MAX`
+/// runs every operation serially.
+pub fn set_parallel_threshold(log2_size: u32) {
+    LOG_PARALLEL_THRESHOLD.store(log2_size, Ordering::Relaxed);
+}
+
+thread_local! {
+    /// The threshold set by `with_parallel_threshold` on the current thread, if any.
+    static LOG_PARALLEL_THRESHOLD_OVERRIDE: Cell<Option<u32>> = const { Cell::new(None) };
+}
+
+/// Runs `f` with the threshold set to `2^log2_size` elements on the current thread,
+/// leaving the threshold of every other thread unchanged.
+///
+/// Operations that `f` dispatches to the thread pool use the threshold of the pool's threads.
+pub fn with_parallel_threshold<T>(log2_size: u32, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous threshold of the current thread, even if `f` panics.
+    struct Reset(Option<u32>);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            LOG_PARALLEL_THRESHOLD_OVERRIDE.with(|threshold| threshold.set(self.0));
+        }
+    }
+
+    let _reset = Reset(LOG_PARALLEL_THRESHOLD_OVERRIDE.with(|threshold| threshold.replace(Some(log2_size))));
+    f()
+}
+
+/// Returns the base-2 logarithm of the number of elements below which operations run serially.
+pub fn parallel_threshold() -> u32 {
+    LOG_PARALLEL_THRESHOLD_OVERRIDE
+        .with(|threshold| threshold.get())
+        .unwrap_or_else(|| LOG_PARALLEL_THRESHOLD.load(Ordering::Relaxed))
+}
+
+/// Returns `true` if an operation on `num_elements` elements should run in parallel.
+#[inline]
+pub(crate) fn is_parallel(num_elements: usize) -> bool {
+    cfg!(feature = "parallel") && num_elements.checked_shr(parallel_threshold()).map_or(false, |n| n > 0)
+}
+
+/// Runs `f` with the maximum available threads if an operation on `num_elements` elements
+/// should run in parallel, and on the current thread otherwise.
+#[inline]
+pub(crate) fn execute_with_threshold<T: Sync + Send>(num_elements: usize, f: impl FnOnce() -> T + Send) -> T {
+    match is_parallel(num_elements) {
+        true => execute_with_max_available_threads(f),
+        false => f(),
+    }
+}
+
+/// Multiplies each element of `lhs` by the corresponding element of `rhs`.
+pub(crate) fn mul_assign_pointwise<F: Field>(lhs: &mut [F], rhs: &[F]) {
+    match is_parallel(lhs.len()) {
+        true => cfg_iter_mut!(lhs).zip(rhs).for_each(|(a, b)| *a *= b),
+        false => lhs.iter_mut().zip(rhs).for_each(|(a, b)| *a *= b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::{DensePolynomial, EvaluationDomain};
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::Zero;
+
+    use rand::thread_rng;
+    use std::{
+        collections::HashSet,
+        ops::{Add, AddAssign, MulAssign, Sub, SubAssign},
+        sync::Mutex,
+        thread::ThreadId,
+    };
+
+    /// The threads on which a `ThreadTracked` element has been multiplied.
+    static THREADS: Mutex<Option<HashSet<ThreadId>>> = Mutex::new(None);
+
+    /// A field element which records the threads on which it is multiplied.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    struct ThreadTracked(Fr);
+
+    impl Zero for ThreadTracked {
+        fn zero() -> Self {
+            Self(Fr::zero())
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0.is_zero()
+        }
+    }
+
+    impl Add for ThreadTracked {
+        type Output = Self;
+
+        fn add(self, other: Self) -> Self {
+            Self(self.0 + other.0)
+        }
+    }
+
+    impl Sub for ThreadTracked {
+        type Output = Self;
+
+        fn sub(self, other: Self) -> Self {
+            Self(self.0 - other.0)
+        }
+    }
+
+    impl AddAssign for ThreadTracked {
+        fn add_assign(&mut self, other: Self) {
+            self.0 += other.0;
+        }
+    }
+
+    impl SubAssign for ThreadTracked {
+        fn sub_assign(&mut self, other: Self) {
+            self.0 -= other.0;
+        }
+    }
+
+    impl MulAssign<Fr> for ThreadTracked {
+        fn mul_assign(&mut self, other: Fr) {
+            THREADS.lock().unwrap().get_or_insert_with(HashSet::new).insert(std::thread::current().id());
+            self.0 *= other;
+        }
+    }
+
+    /// Returns the threads on which the FFT of `coeffs` over `domain` multiplies field elements.
+    fn fft_threads(domain: EvaluationDomain<Fr>, coeffs: &[Fr]) -> (Vec<Fr>, HashSet<ThreadId>) {
+        *THREADS.lock().unwrap() = None;
+        let mut coeffs = coeffs.iter().copied().map(ThreadTracked).collect::<Vec<_>>();
+        domain.fft_in_place(&mut coeffs);
+        let threads = THREADS.lock().unwrap().take().unwrap_or_default();
+        (coeffs.into_iter().map(|c| c.0).collect(), threads)
+    }
+
+    #[test]
+    fn test_parallel_threshold() {
+        // The domain is small enough to not be offloaded to the GPU.
+        let domain = EvaluationDomain::<Fr>::new(1 << 10).unwrap();
+        let coeffs = DensePolynomial::<Fr>::rand(domain.size() - 1, &mut thread_rng()).coeffs;
+        let expected = domain.fft(&coeffs);
+
+        // Below the threshold, the FFT runs entirely on the current thread.
+        with_parallel_threshold(11, || {
+            assert!(!is_parallel(domain.size()));
+            let (evaluations, threads) = fft_threads(domain, &coeffs);
+            assert_eq!(evaluations, expected);
+            assert_eq!(threads, HashSet::from([std::thread::current().id()]));
+        });
+
+        // At the threshold, the FFT runs in the thread pool.
+        with_parallel_threshold(10, || {
+            assert_eq!(is_parallel(domain.size()), cfg!(feature = "parallel"));
+            let (evaluations, threads) = fft_threads(domain, &coeffs);
+            assert_eq!(evaluations, expected);
+            #[cfg(feature = "parallel")]
+            assert!(!threads.contains(&std::thread::current().id()));
+        });
+
+        // Extreme thresholds do not overflow.
+        with_parallel_threshold(u32::MAX, || assert!(!is_parallel(usize::MAX)));
+        with_parallel_threshold(0, || {
+            assert_eq!(is_parallel(1), cfg!(feature = "parallel"));
+            assert!(!is_parallel(0));
+        });
+    }
+
+    #[test]
+    fn test_with_parallel_threshold_is_scoped() {
+        let threshold = parallel_threshold();
+
+        // The override is visible on the current thread only, and is nested.
+        with_parallel_threshold(3, || {
+            assert_eq!(parallel_threshold(), 3);
+            std::thread::spawn(move || assert_eq!(parallel_threshold(), threshold)).join().unwrap();
+            with_parallel_threshold(5, || assert_eq!(parallel_threshold(), 5));
+            assert_eq!(parallel_threshold(), 3);
+        });
+        assert_eq!(parallel_threshold(), threshold);
+
+        // The override is reset if the operation panics.
+        assert!(std::panic::catch_unwind(|| with_parallel_threshold(3, || panic!())).is_err());
+        assert_eq!(parallel_threshold(), threshold);
+    }
+}
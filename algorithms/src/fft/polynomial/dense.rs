@@ -16,11 +16,27 @@
 
 //! A polynomial represented in coefficient form.
 
-use crate::fft::{EvaluationDomain, Evaluations, Polynomial};
+use crate::fft::{
+    domain::{FFTPrecomputation, IFFTPrecomputation},
+    parallel::is_parallel,
+    EvaluationDomain,
+    Evaluations,
+    InterpolationError,
+    Polynomial,
+};
 use snarkvm_fields::{Field, PrimeField};
-use snarkvm_utilities::{cfg_iter_mut, serialize::*};
+use snarkvm_utilities::{
+    cfg_chunks,
+    cfg_iter,
+    cfg_iter_mut,
+    check_length_bounded,
+    read_vec_exact,
+    serialize::*,
+    SerializeExt,
+};
 
 use rand::Rng;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt,
     ops::{Add, AddAssign, Deref, DerefMut, Div, Mul, MulAssign, Neg, Sub, SubAssign},
@@ -32,7 +48,45 @@ use itertools::Itertools;
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
-use super::PolyMultiplier;
+use super::{karatsuba::karatsuba_mul, MulScratch, PolyMultiplier, SubproductTree};
+
+/// The minimum number of points for which `DensePolynomial::evaluate_many` uses a subproduct tree.
+const MIN_NUM_POINTS_FOR_SUBPRODUCT_TREE: usize = 1 << 6;
+
+/// The minimum degree for which `DensePolynomial::evaluate_many` uses a subproduct tree.
+const MIN_DEGREE_FOR_SUBPRODUCT_TREE: usize = 1 << 6;
+
+/// The minimum number of coefficients for which `DensePolynomial::evaluate` evaluates in parallel.
+const MIN_NUM_COEFFS_FOR_PARALLEL_EVALUATION: usize = 1 << 12;
+
+/// The minimum number of coefficients in each chunk of `DensePolynomial::evaluate_parallel`.
+const MIN_CHUNK_SIZE_FOR_PARALLEL_EVALUATION: usize = 1 << 10;
+
+/// Returns `true` if products with `num_coeffs` coefficients can be computed by FFTs over `F`.
+fn supports_fft_mul<F: PrimeField>(num_coeffs: usize) -> bool {
+    EvaluationDomain::<F>::compute_size_of_domain(num_coeffs).is_some()
+}
+
+/// Evaluates the polynomial with the given coefficients at `point`, using Horner's method.
+fn horner<F: Field>(coeffs: &[F], point: F) -> F {
+    coeffs.iter().rev().fold(F::zero(), |result, coeff| result * point + coeff)
+}
+
+/// The maximum number of field elements that `FromBytes` reads for a `DensePolynomial` or for `Evaluations`.
+pub const MAX_NUM_SERIALIZED_ELEMENTS: u64 = 1 << 28;
+
+/// Writes the given field elements to a buffer, prefixed by their number as a `u64`.
+pub(crate) fn write_field_elements<F: Field, W: Write>(elements: &[F], mut writer: W) -> io::Result<()> {
+    (elements.len() as u64).write_le(&mut writer)?;
+    elements.iter().try_for_each(|element| element.write_le(&mut writer))
+}
+
+/// Reads at most `max_num_elements` field elements from a buffer, prefixed by their number as a `u64`.
+pub(crate) fn read_field_elements<F: Field, R: Read>(mut reader: R, max_num_elements: u64) -> io::Result<Vec<F>> {
+    let num_elements: u64 = FromBytes::read_le(&mut reader)?;
+    let num_elements = check_length_bounded(num_elements, usize::try_from(max_num_elements).unwrap_or(usize::MAX))?;
+    read_vec_exact(reader, num_elements)
+}
 
 /// Stores a polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
@@ -74,15 +128,27 @@ impl<F: Field> DensePolynomial<F> {
     }
 
     /// Constructs a new polynomial from a list of coefficients.
-    pub fn from_coefficients_vec(mut coeffs: Vec<F>) -> Self {
-        // While there are zeros at the end of the coefficient vector, pop them off.
-        while coeffs.last().map_or(false, |c| c.is_zero()) {
-            coeffs.pop();
-        }
+    pub fn from_coefficients_vec(coeffs: Vec<F>) -> Self {
+        let mut result = Self { coeffs };
+        result.truncate_leading_zeros();
         // Check that either the coefficients vec is empty or that the last coeff is non-zero.
-        assert!(coeffs.last().map_or(true, |coeff| !coeff.is_zero()));
+        assert!(result.coeffs.last().map_or(true, |coeff| !coeff.is_zero()));
 
-        Self { coeffs }
+        result
+    }
+
+    /// Removes the zero coefficients at the end of `self.coeffs`, which are the leading zero
+    /// coefficients of the polynomial. All arithmetic on `DensePolynomial` maintains that there are none,
+    /// so this is only needed after modifying `self.coeffs` directly.
+    pub fn truncate_leading_zeros(&mut self) {
+        while self.coeffs.last().map_or(false, |coeff| coeff.is_zero()) {
+            self.coeffs.pop();
+        }
+    }
+
+    /// Adds `scalar * other` to `self` in place, without allocating a scaled copy of `other`.
+    pub fn add_assign_scaled(&mut self, other: &Self, scalar: F) {
+        *self += (scalar, other);
     }
 
     /// Returns the degree of the polynomial.
@@ -90,7 +156,10 @@ impl<F: Field> DensePolynomial<F> {
         if self.is_zero() {
             0
         } else {
-            assert!(self.coeffs.last().map_or(false, |coeff| !coeff.is_zero()));
+            assert!(
+                self.coeffs.last().map_or(false, |coeff| !coeff.is_zero()),
+                "the coefficients of a polynomial must not have trailing zeros"
+            );
             self.coeffs.len() - 1
         }
     }
@@ -102,15 +171,49 @@ impl<F: Field> DensePolynomial<F> {
         } else if point.is_zero() {
             return self.coeffs[0];
         }
-        let mut powers_of_point = vec![F::one()];
-        let mut cur = point;
-        for _ in 0..self.degree() {
-            powers_of_point.push(cur);
-            cur *= point;
+        match self.coeffs.len() < MIN_NUM_COEFFS_FOR_PARALLEL_EVALUATION || !is_parallel(self.coeffs.len()) {
+            true => horner(&self.coeffs, point),
+            false => self.evaluate_parallel(point),
+        }
+    }
+
+    /// Evaluates `self` at the given `point` in the field, by splitting the coefficients into one chunk per thread,
+    /// evaluating each chunk with Horner's method, and recombining the results.
+    pub fn evaluate_parallel(&self, point: F) -> F {
+        #[cfg(feature = "parallel")]
+        let num_chunks = snarkvm_utilities::parallel::max_available_threads();
+        #[cfg(not(feature = "parallel"))]
+        let num_chunks = 1;
+
+        let chunk_size = (self.coeffs.len() + num_chunks - 1) / num_chunks;
+        self.evaluate_in_chunks(point, chunk_size.max(MIN_CHUNK_SIZE_FOR_PARALLEL_EVALUATION))
+    }
+
+    /// Evaluates `self` at the given `point` in the field, using chunks of `chunk_size` coefficients.
+    fn evaluate_in_chunks(&self, point: F, chunk_size: usize) -> F {
+        let chunk_size = chunk_size.max(1);
+        let chunk_evaluations =
+            cfg_chunks!(self.coeffs, chunk_size).map(|chunk| horner(chunk, point)).collect::<Vec<_>>();
+        // The `i`-th chunk is offset by `point^{i * chunk_size}`, so the chunk evaluations
+        // are recombined by evaluating them as coefficients at `point^chunk_size`.
+        horner(&chunk_evaluations, point.pow([chunk_size as u64]))
+    }
+
+    /// Divides `self` by `X - point` using synthetic division.
+    /// Returns the quotient and the remainder of the division, which is the evaluation of `self` at `point`.
+    pub fn divide_by_linear(&self, point: F) -> (DensePolynomial<F>, F) {
+        let (constant, coeffs) = match self.coeffs.split_first() {
+            Some(split) => split,
+            None => return (DensePolynomial::zero(), F::zero()),
+        };
+        // The coefficients of the quotient satisfy `q_{i - 1} = a_i + point * q_i`.
+        let mut quotient = coeffs.to_vec();
+        for i in (0..quotient.len().saturating_sub(1)).rev() {
+            let next = quotient[i + 1];
+            quotient[i] += point * next;
         }
-        let zero = F::zero();
-        let mapping = crate::cfg_into_iter!(powers_of_point).zip_eq(&self.coeffs).map(|(power, coeff)| power * coeff);
-        crate::cfg_reduce!(mapping, || zero, |a, b| a + b)
+        let remainder = *constant + quotient.first().map_or(F::zero(), |coeff| point * coeff);
+        (DensePolynomial::from_coefficients_vec(quotient), remainder)
     }
 
     /// Outputs a polynomial of degree `d` where each coefficient is sampled uniformly at random
@@ -151,15 +254,37 @@ impl<F: PrimeField> DensePolynomial<F> {
         DensePolynomial::from_coefficients_vec(shifted)
     }
 
+    /// Returns `self + Z_H(X) * r(X)`, where `Z_H` is the vanishing polynomial of `domain`,
+    /// and `r` is a random polynomial of degree `hiding_degree`.
+    ///
+    /// The masked polynomial agrees with `self` on `domain`, while up to `hiding_degree + 1`
+    /// of its evaluations outside of `domain` reveal nothing about `self`.
+    pub fn mask<R: Rng>(&self, domain: &EvaluationDomain<F>, hiding_degree: usize, rng: &mut R) -> Self {
+        let mut masked = DensePolynomial::rand(hiding_degree, rng).mul_by_vanishing_poly(*domain);
+        masked += self;
+        masked
+    }
+
     /// Divide `self` by the vanishing polynomial for the domain `domain`.
     /// Returns the quotient and remainder of the division.
     pub fn divide_by_vanishing_poly(
         &self,
         domain: EvaluationDomain<F>,
     ) -> Option<(DensePolynomial<F>, DensePolynomial<F>)> {
-        let self_poly = Polynomial::from(self);
-        let vanishing_poly = Polynomial::from(domain.vanishing_polynomial());
-        self_poly.divide_with_q_and_r(&vanishing_poly)
+        let size = domain.size();
+        if self.coeffs.len() <= size {
+            return Some((DensePolynomial::zero(), self.clone()));
+        }
+        // As the vanishing polynomial is `X^size - 1`, the coefficients of the quotient
+        // satisfy `q_i = a_{i + size} + q_{i + size}`, and those of the remainder satisfy `r_i = a_i + q_i`.
+        let mut quotient = self.coeffs[size..].to_vec();
+        for i in (0..quotient.len().saturating_sub(size)).rev() {
+            let next = quotient[i + size];
+            quotient[i] += next;
+        }
+        let mut remainder = self.coeffs[..size].to_vec();
+        remainder.iter_mut().zip(&quotient).for_each(|(r, q)| *r += q);
+        Some((DensePolynomial::from_coefficients_vec(quotient), DensePolynomial::from_coefficients_vec(remainder)))
     }
 
     /// Evaluate `self` over `domain`.
@@ -173,6 +298,114 @@ impl<F: PrimeField> DensePolynomial<F> {
         let poly: Polynomial<'_, F> = self.into();
         Polynomial::<F>::evaluate_over_domain(poly, domain)
     }
+
+    /// Evaluate `self` over `domain`, reusing the roots of unity in `pc`.
+    ///
+    /// `pc` may be a precomputation for `domain` or for any larger domain.
+    pub fn evaluate_over_domain_with_pc(
+        &self,
+        domain: EvaluationDomain<F>,
+        pc: &FFTPrecomputation<F>,
+    ) -> Evaluations<F> {
+        assert!(self.coeffs.len() <= domain.size(), "the polynomial does not fit in the domain");
+        Evaluations::from_vec_and_domain(domain.in_order_fft_with_pc(&self.coeffs, pc), domain)
+    }
+
+    /// Multiplies `self` by `other`, reusing the roots of unity in `fft_pc` and `ifft_pc`.
+    ///
+    /// Returns `None` if the precomputations are for a domain that is too small for the product.
+    pub fn mul_with_pc(
+        &self,
+        other: &Self,
+        fft_pc: &FFTPrecomputation<F>,
+        ifft_pc: &IFFTPrecomputation<F>,
+    ) -> Option<DensePolynomial<F>> {
+        if self.is_zero() || other.is_zero() {
+            return Some(DensePolynomial::zero());
+        }
+        let domain = EvaluationDomain::new(self.degree() + other.degree() + 1)?;
+        // Ensure the precomputations cover the product domain.
+        fft_pc.precomputation_for_subdomain(&domain)?;
+        ifft_pc.precomputation_for_subdomain(&domain)?;
+
+        let mut product = self.evaluate_over_domain_with_pc(domain, fft_pc);
+        product *= &other.evaluate_over_domain_with_pc(domain, fft_pc);
+        Some(product.interpolate_with_pc(ifft_pc))
+    }
+
+    /// Evaluates `self` at each of the given `points`.
+    ///
+    /// For many points and large degrees, this uses a subproduct tree, which takes `O(n log^2 n)` field operations.
+    /// Otherwise, `self` is evaluated at each point separately.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        if points.len() < MIN_NUM_POINTS_FOR_SUBPRODUCT_TREE || self.degree() < MIN_DEGREE_FOR_SUBPRODUCT_TREE {
+            cfg_iter!(points).map(|point| self.evaluate(*point)).collect()
+        } else {
+            SubproductTree::new(points).evaluate(self)
+        }
+    }
+
+    /// Returns the unique polynomial of degree less than `points.len()` which takes the value `values[i]` at `points[i]`.
+    ///
+    /// If the points are the elements of an evaluation domain, in order, this is an iFFT.
+    /// Otherwise, this uses a subproduct tree, which takes `O(n log^2 n)` field operations.
+    pub fn interpolate(points: &[F], values: &[F]) -> Result<Self, InterpolationError> {
+        if points.len() != values.len() {
+            return Err(InterpolationError::LengthMismatch { num_points: points.len(), num_values: values.len() });
+        }
+        if let Some(domain) = EvaluationDomain::<F>::new(points.len()).filter(|domain| domain.size() == points.len()) {
+            if domain.elements().zip(points).all(|(element, point)| element == *point) {
+                return Ok(Self::from_coefficients_vec(domain.ifft(values)));
+            }
+        }
+        // The lengths match, so the subproduct tree only fails on repeated points.
+        SubproductTree::new(points).interpolate(values).ok_or(InterpolationError::DuplicatePoints)
+    }
+
+    /// Multiplies `self` by `other` using the buffers in `scratch`, and returns the coefficients of the product.
+    /// Once `scratch` has been used, this does not allocate any vectors of the size of the domain.
+    ///
+    /// Returns `None` if the product does not fit in the domain of `scratch`.
+    pub fn mul_in_place_with_scratch<'a>(&self, other: &Self, scratch: &'a mut MulScratch<F>) -> Option<&'a [F]> {
+        scratch.multiply(self, other)
+    }
+}
+
+impl<F: Field> DensePolynomial<F> {
+    /// Reads a polynomial with at most `max_num_coeffs` coefficients from a buffer.
+    /// Trailing zero coefficients are removed, so that `degree` is meaningful for the result.
+    pub fn read_le_with_max_num_coeffs<R: Read>(reader: R, max_num_coeffs: u64) -> io::Result<Self> {
+        Ok(Self::from_coefficients_vec(read_field_elements(reader, max_num_coeffs)?))
+    }
+}
+
+impl<F: Field> FromBytes for DensePolynomial<F> {
+    /// Reads a polynomial with at most `MAX_NUM_SERIALIZED_ELEMENTS` coefficients from a buffer.
+    fn read_le<R: Read>(reader: R) -> io::Result<Self> {
+        Self::read_le_with_max_num_coeffs(reader, MAX_NUM_SERIALIZED_ELEMENTS)
+    }
+}
+
+impl<F: Field> ToBytes for DensePolynomial<F> {
+    /// Writes the coefficients of the polynomial, without trailing zeros, to a buffer.
+    fn write_le<W: Write>(&self, writer: W) -> io::Result<()> {
+        let num_coeffs = self.coeffs.iter().rposition(|coeff| !coeff.is_zero()).map_or(0, |i| i + 1);
+        write_field_elements(&self.coeffs[..num_coeffs], writer)
+    }
+}
+
+impl<F: Field> Serialize for DensePolynomial<F> {
+    /// Serializes the polynomial to a hex string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize_hex_or_bytes(serializer)
+    }
+}
+
+impl<'de, F: Field> Deserialize<'de> for DensePolynomial<F> {
+    /// Deserializes the polynomial from a hex string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::deserialize_hex_or_bytes(deserializer)
+    }
 }
 
 impl<F: Field> From<super::SparsePolynomial<F>> for DensePolynomial<F> {
@@ -197,15 +430,13 @@ impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
             let mut result = self.clone();
             // Zip safety: `result` and `other` could have different lengths.
             cfg_iter_mut!(result.coeffs).zip(&other.coeffs).for_each(|(a, b)| *a += b);
+            // If the leading coefficients cancel, pop them off.
+            result.truncate_leading_zeros();
             result
         } else {
             let mut result = other.clone();
             // Zip safety: `result` and `other` could have different lengths.
             cfg_iter_mut!(result.coeffs).zip(&self.coeffs).for_each(|(a, b)| *a += b);
-            // If the leading coefficient ends up being zero, pop it off.
-            while result.coeffs.last().unwrap().is_zero() {
-                result.coeffs.pop();
-            }
             result
         }
     }
@@ -221,15 +452,13 @@ impl<'a, F: Field> AddAssign<&'a DensePolynomial<F>> for DensePolynomial<F> {
         } else if self.degree() >= other.degree() {
             // Zip safety: `self` and `other` could have different lengths.
             cfg_iter_mut!(self.coeffs).zip(&other.coeffs).for_each(|(a, b)| *a += b);
+            // If the leading coefficients cancel, pop them off.
+            self.truncate_leading_zeros();
         } else {
             // Add the necessary number of zero coefficients.
             self.coeffs.resize(other.coeffs.len(), F::zero());
             // Zip safety: `self` and `other` have the same length.
             cfg_iter_mut!(self.coeffs).zip(&other.coeffs).for_each(|(a, b)| *a += b);
-            // If the leading coefficient ends up being zero, pop it off.
-            while self.coeffs.last().unwrap().is_zero() {
-                self.coeffs.pop();
-            }
         }
     }
 }
@@ -273,11 +502,9 @@ impl<'a, F: Field> AddAssign<(F, &'a DensePolynomial<F>)> for DensePolynomial<F>
             cfg_iter_mut!(self.coeffs).zip(&other.coeffs).for_each(|(a, b)| {
                 *a += f * b;
             });
-            // If the leading coefficient ends up being zero, pop it off.
-            while self.coeffs.last().unwrap().is_zero() {
-                self.coeffs.pop();
-            }
         }
+        // If the leading coefficients cancel, or `f` is zero, pop them off.
+        self.truncate_leading_zeros();
     }
 }
 
@@ -310,6 +537,8 @@ impl<'a, 'b, F: Field> Sub<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
             let mut result = self.clone();
             // Zip safety: `result` and `other` could have different degrees.
             cfg_iter_mut!(result.coeffs).zip(&other.coeffs).for_each(|(a, b)| *a -= b);
+            // If the leading coefficients cancel, pop them off.
+            result.truncate_leading_zeros();
             result
         } else {
             let mut result = self.clone();
@@ -318,13 +547,6 @@ impl<'a, 'b, F: Field> Sub<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
             cfg_iter_mut!(result.coeffs).zip(&other.coeffs).for_each(|(a, b)| {
                 *a -= b;
             });
-            if !result.is_zero() {
-                // If the leading coefficient ends up being zero, pop it off.
-                while result.coeffs.last().unwrap().is_zero() {
-                    result.coeffs.pop();
-                }
-            }
-
             result
         }
     }
@@ -343,15 +565,13 @@ impl<'a, F: Field> SubAssign<&'a DensePolynomial<F>> for DensePolynomial<F> {
         } else if self.degree() >= other.degree() {
             // Zip safety: self and other could have different lengths.
             cfg_iter_mut!(self.coeffs).zip(&other.coeffs).for_each(|(a, b)| *a -= b);
+            // If the leading coefficients cancel, pop them off.
+            self.truncate_leading_zeros();
         } else {
             // Add the necessary number of zero coefficients.
             self.coeffs.resize(other.coeffs.len(), F::zero());
             // Zip safety: self and other have the same length after the resize.
             cfg_iter_mut!(self.coeffs).zip(&other.coeffs).for_each(|(a, b)| *a -= b);
-            // If the leading coefficient ends up being zero, pop it off.
-            while self.coeffs.last().unwrap().is_zero() {
-                self.coeffs.pop();
-            }
         }
     }
 }
@@ -359,16 +579,14 @@ impl<'a, F: Field> SubAssign<&'a DensePolynomial<F>> for DensePolynomial<F> {
 impl<'a, F: Field> AddAssign<&'a super::SparsePolynomial<F>> for DensePolynomial<F> {
     #[inline]
     fn add_assign(&mut self, other: &'a super::SparsePolynomial<F>) {
-        if self.degree() < other.degree() {
+        if self.coeffs.len() <= other.degree() {
             self.coeffs.resize(other.degree() + 1, F::zero());
         }
         for (i, b) in other.coeffs() {
             self.coeffs[*i] += b;
         }
-        // If the leading coefficient ends up being zero, pop it off.
-        while let Some(true) = self.coeffs.last().map(|c| c.is_zero()) {
-            self.coeffs.pop();
-        }
+        // If the leading coefficients cancel, pop them off.
+        self.truncate_leading_zeros();
     }
 }
 
@@ -377,16 +595,14 @@ impl<'a, F: Field> Sub<&'a super::SparsePolynomial<F>> for DensePolynomial<F> {
 
     #[inline]
     fn sub(mut self, other: &'a super::SparsePolynomial<F>) -> Self::Output {
-        if self.degree() < other.degree() {
+        if self.coeffs.len() <= other.degree() {
             self.coeffs.resize(other.degree() + 1, F::zero());
         }
         for (i, b) in other.coeffs() {
             self.coeffs[*i] -= b;
         }
-        // If the leading coefficient ends up being zero, pop it off.
-        while let Some(true) = self.coeffs.last().map(|c| c.is_zero()) {
-            self.coeffs.pop();
-        }
+        // If the leading coefficients cancel, pop them off.
+        self.truncate_leading_zeros();
         self
     }
 }
@@ -403,6 +619,7 @@ impl<'a, 'b, F: Field> Div<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
 }
 
 /// Performs O(nlogn) multiplication of polynomials if F is smooth.
+/// Otherwise, if `F` lacks a large enough two-adic subgroup for the product, uses Karatsuba multiplication.
 impl<'a, 'b, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
     type Output = DensePolynomial<F>;
 
@@ -411,6 +628,8 @@ impl<'a, 'b, F: PrimeField> Mul<&'a DensePolynomial<F>> for &'b DensePolynomial<
     fn mul(self, other: &'a DensePolynomial<F>) -> DensePolynomial<F> {
         if self.is_zero() || other.is_zero() {
             DensePolynomial::zero()
+        } else if !supports_fft_mul::<F>(self.degree() + other.degree() + 1) {
+            DensePolynomial::from_coefficients_vec(karatsuba_mul(&self.coeffs, &other.coeffs))
         } else {
             let mut m = PolyMultiplier::new();
             m.add_polynomial_ref(self, "");
@@ -426,7 +645,7 @@ impl<F: Field> Mul<F> for DensePolynomial<F> {
 
     #[inline]
     fn mul(mut self, other: F) -> Self {
-        self.iter_mut().for_each(|c| *c *= other);
+        self *= other;
         self
     }
 }
@@ -446,7 +665,10 @@ impl<'a, F: Field> Mul<F> for &'a DensePolynomial<F> {
 impl<F: Field> MulAssign<F> for DensePolynomial<F> {
     #[allow(clippy::suspicious_arithmetic_impl)]
     fn mul_assign(&mut self, other: F) {
-        cfg_iter_mut!(self).for_each(|c| *c *= other);
+        match other.is_zero() {
+            true => self.coeffs.clear(),
+            false => cfg_iter_mut!(self).for_each(|c| *c *= other),
+        }
     }
 }
 
@@ -473,12 +695,12 @@ impl<F: Field> DerefMut for DensePolynomial<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::fft::polynomial::*;
+    use crate::fft::{polynomial::*, InterpolationError};
     use snarkvm_curves::bls12_377::Fr;
     use snarkvm_fields::{Field, One, Zero};
-    use snarkvm_utilities::rand::Uniform;
+    use snarkvm_utilities::{assert_serde_consistency, rand::Uniform, FromBytes, ToBytes};
 
-    use rand::thread_rng;
+    use rand::{thread_rng, Rng};
 
     #[test]
     fn double_polynomials_random() {
@@ -597,12 +819,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn mul_polynomials_without_fft() {
+        use snarkvm_curves::edwards_bls12::Fr as LowTwoAdicityFr;
+
+        // The scalar field of Edwards BLS12 only has a subgroup of order 2.
+        assert!(super::supports_fft_mul::<LowTwoAdicityFr>(2));
+        assert!(!super::supports_fft_mul::<LowTwoAdicityFr>(3));
+        assert!(super::supports_fft_mul::<Fr>(1 << 20));
+
+        let rng = &mut thread_rng();
+        for a_degree in [0, 1, 2, 40, 100] {
+            for b_degree in [0, 1, 33, 70] {
+                let a = DensePolynomial::<LowTwoAdicityFr>::rand(a_degree, rng);
+                let b = DensePolynomial::<LowTwoAdicityFr>::rand(b_degree, rng);
+                assert_eq!(&a * &b, a.naive_mul(&b));
+            }
+        }
+    }
+
     #[test]
     fn mul_by_vanishing_poly() {
         let rng = &mut thread_rng();
-        for size in 1..10 {
+        for size in 0..10 {
             let domain = EvaluationDomain::new(1 << size).unwrap();
-            for degree in 0..70 {
+            for degree in (0..70).chain([domain.size() - 1, domain.size(), 2 * domain.size() + 1]) {
                 let p = DensePolynomial::<Fr>::rand(degree, rng);
                 let ans1 = p.mul_by_vanishing_poly(domain);
                 let ans2 = &p * &domain.vanishing_polynomial().into();
@@ -610,4 +851,327 @@ mod tests {
             }
         }
     }
+
+    /// Asserts that `masked` is `original` plus a multiple of the vanishing polynomial of `domain`.
+    fn assert_masked(original: &DensePolynomial<Fr>, masked: &DensePolynomial<Fr>, domain: EvaluationDomain<Fr>) {
+        let (_, remainder) = (masked - original).divide_by_vanishing_poly(domain).unwrap();
+        assert!(remainder.is_zero());
+        assert_eq!(masked.evaluate_over_domain_by_ref(domain), original.evaluate_over_domain_by_ref(domain));
+    }
+
+    #[test]
+    fn mask_random() {
+        let rng = &mut thread_rng();
+        for log_size in 0..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            for degree in [0, 1, domain.size() - 1, domain.size(), 2 * domain.size()] {
+                let p = DensePolynomial::<Fr>::rand(degree, rng);
+                for hiding_degree in [0, 1, 3] {
+                    let masked = p.mask(&domain, hiding_degree, rng);
+                    assert_eq!(masked.degree(), core::cmp::max(degree, domain.size() + hiding_degree));
+                    assert_masked(&p, &masked, domain);
+
+                    // Outside of the domain, the evaluations differ with overwhelming probability.
+                    let point = domain.sample_element_outside_domain(rng);
+                    assert_ne!(masked.evaluate(point), p.evaluate(point));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn evaluate_over_domain_with_pc() {
+        let rng = &mut thread_rng();
+        let pc = EvaluationDomain::<Fr>::new(1 << 10).unwrap().precompute_fft();
+        for size in 0..10 {
+            let domain = EvaluationDomain::new(1 << size).unwrap();
+            let p = DensePolynomial::<Fr>::rand((1 << size) - 1, rng);
+            assert_eq!(
+                p.evaluate_over_domain_with_pc(domain, &domain.precompute_fft()),
+                p.evaluate_over_domain_by_ref(domain)
+            );
+            assert_eq!(p.evaluate_over_domain_with_pc(domain, &pc), p.evaluate_over_domain_by_ref(domain));
+        }
+    }
+
+    #[test]
+    fn mul_with_pc_random() {
+        let rng = &mut thread_rng();
+        let domain = EvaluationDomain::<Fr>::new(128).unwrap();
+        let fft_pc = domain.precompute_fft();
+        let ifft_pc = domain.precompute_ifft();
+        for a_degree in 0..64 {
+            for b_degree in 0..64 {
+                let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+                let product = a.mul_with_pc(&b, &fft_pc, &ifft_pc).unwrap();
+                assert_eq!(product, &a * &b);
+                assert_eq!(product, a.naive_mul(&b));
+            }
+        }
+
+        // Precomputations for a smaller domain cannot be used for the product.
+        let a = DensePolynomial::<Fr>::rand(100, rng);
+        assert!(a.mul_with_pc(&a, &fft_pc, &ifft_pc).is_none());
+    }
+
+    #[test]
+    fn evaluate_many_random() {
+        let rng = &mut thread_rng();
+        for num_points in [0, 1, 10, 63, 64, 200] {
+            let mut points = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            // Include repeated points.
+            points.extend_from_within(..num_points / 2);
+            for degree in [0, 1, 63, 64, 300] {
+                let p = DensePolynomial::<Fr>::rand(degree, rng);
+                let expected = points.iter().map(|point| p.evaluate(*point)).collect::<Vec<_>>();
+                assert_eq!(p.evaluate_many(&points), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn interpolate_random() {
+        let rng = &mut thread_rng();
+        for num_points in [1, 2, 10, 100] {
+            let points = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let p = DensePolynomial::<Fr>::rand(num_points - 1, rng);
+            let values = p.evaluate_many(&points);
+            assert_eq!(DensePolynomial::interpolate(&points, &values).unwrap(), p);
+
+            // Interpolating arbitrary values recovers them at the points.
+            let values = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let q = DensePolynomial::interpolate(&points, &values).unwrap();
+            assert!(q.coeffs.len() <= num_points);
+            assert_eq!(q.evaluate_many(&points), values);
+        }
+    }
+
+    #[test]
+    fn interpolate_over_domain() {
+        let rng = &mut thread_rng();
+        for num_points in [1, 2, 16, 256] {
+            let domain = EvaluationDomain::<Fr>::new(num_points).unwrap();
+            let p = DensePolynomial::<Fr>::rand(num_points - 1, rng);
+            let values = p.evaluate_over_domain_by_ref(domain).evaluations;
+
+            // The elements of the domain, in order, are interpolated by an iFFT.
+            let points = domain.elements().collect::<Vec<_>>();
+            assert_eq!(DensePolynomial::interpolate(&points, &values).unwrap(), p);
+
+            // Any other order falls back to the subproduct tree.
+            let points = points.into_iter().rev().collect::<Vec<_>>();
+            let values = values.into_iter().rev().collect::<Vec<_>>();
+            assert_eq!(DensePolynomial::interpolate(&points, &values).unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn interpolate_invalid() {
+        let rng = &mut thread_rng();
+        let mut points = (0..10).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let values = (0..10).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        assert_eq!(
+            DensePolynomial::interpolate(&points, &values[..9]),
+            Err(InterpolationError::LengthMismatch { num_points: 10, num_values: 9 })
+        );
+        points[5] = points[2];
+        assert_eq!(DensePolynomial::interpolate(&points, &values), Err(InterpolationError::DuplicatePoints));
+    }
+
+    #[test]
+    fn evaluate_parallel_random() {
+        let rng = &mut thread_rng();
+        for degree in [0, 1, 2, 10, 1023, 1024, 1025, 5000] {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let point = Fr::rand(rng);
+            let expected = super::horner(&p.coeffs, point);
+            assert_eq!(p.evaluate(point), expected);
+            assert_eq!(p.evaluate_parallel(point), expected);
+            // Check a single chunk, chunks of a single coefficient, and chunks that do not divide the degree.
+            for chunk_size in [0, 1, 2, 3, 7, 64, degree, degree + 1, degree + 2] {
+                assert_eq!(p.evaluate_in_chunks(point, chunk_size), expected);
+            }
+        }
+
+        // Check the zero polynomial and the zero point.
+        assert!(DensePolynomial::<Fr>::zero().evaluate_parallel(Fr::rand(rng)).is_zero());
+        let p = DensePolynomial::<Fr>::rand(5000, rng);
+        assert_eq!(p.evaluate_parallel(Fr::zero()), p.coeffs[0]);
+    }
+
+    #[test]
+    fn divide_by_linear_random() {
+        let rng = &mut thread_rng();
+        for degree in 0..70 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let point = Fr::rand(rng);
+            let (quotient, remainder) = p.divide_by_linear(point);
+            assert_eq!(remainder, p.evaluate(point));
+
+            let divisor = DensePolynomial::from_coefficients_vec(vec![-point, Fr::one()]);
+            let (expected_quotient, expected_remainder) =
+                Polynomial::divide_with_q_and_r(&(&p).into(), &(&divisor).into()).unwrap();
+            assert_eq!(quotient, expected_quotient);
+            assert_eq!(DensePolynomial::from_coefficients_vec(vec![remainder]), expected_remainder);
+            assert_eq!(&(&quotient * &divisor) + &DensePolynomial::from_coefficients_vec(vec![remainder]), p);
+        }
+        assert_eq!(
+            DensePolynomial::<Fr>::zero().divide_by_linear(Fr::rand(rng)),
+            (DensePolynomial::zero(), Fr::zero())
+        );
+    }
+
+    #[test]
+    fn divide_by_vanishing_poly_random() {
+        let rng = &mut thread_rng();
+        for size in 0..8 {
+            let domain = EvaluationDomain::<Fr>::new(1 << size).unwrap();
+            let vanishing_polynomial = DensePolynomial::from(domain.vanishing_polynomial());
+            for degree in 0..70 {
+                let p = DensePolynomial::<Fr>::rand(degree, rng);
+                let (quotient, remainder) = p.divide_by_vanishing_poly(domain).unwrap();
+                assert!(remainder.is_zero() || remainder.degree() < domain.size());
+                assert_eq!(&(&quotient * &vanishing_polynomial) + &remainder, p);
+
+                let expected =
+                    Polynomial::divide_with_q_and_r(&(&p).into(), &domain.vanishing_polynomial().into()).unwrap();
+                assert_eq!((quotient, remainder), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn bytes_random() {
+        let rng = &mut thread_rng();
+        for degree in 0..70 {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let bytes = p.to_bytes_le().unwrap();
+            assert_eq!(bytes.len(), 8 + 32 * (degree + 1));
+            assert_eq!(DensePolynomial::read_le(&bytes[..]).unwrap(), p);
+
+            // Truncated inputs are rejected.
+            assert!(DensePolynomial::<Fr>::read_le(&bytes[..bytes.len() - 1]).is_err());
+            assert!(DensePolynomial::<Fr>::read_le(&bytes[..7]).is_err());
+
+            // The maximum number of coefficients is enforced.
+            assert_eq!(DensePolynomial::read_le_with_max_num_coeffs(&bytes[..], degree as u64 + 1).unwrap(), p);
+            assert!(DensePolynomial::<Fr>::read_le_with_max_num_coeffs(&bytes[..], degree as u64).is_err());
+        }
+        let zero = DensePolynomial::<Fr>::zero();
+        assert_eq!(DensePolynomial::read_le(&zero.to_bytes_le().unwrap()[..]).unwrap(), zero);
+    }
+
+    #[test]
+    fn serde_random() {
+        let rng = &mut thread_rng();
+        for degree in 0..70 {
+            assert_serde_consistency!(DensePolynomial<Fr>, DensePolynomial::rand(degree, rng));
+        }
+        assert_serde_consistency!(DensePolynomial<Fr>, DensePolynomial::zero());
+    }
+
+    #[test]
+    fn bytes_trailing_zeros() {
+        let rng = &mut thread_rng();
+        let p = DensePolynomial::<Fr>::rand(10, rng);
+        let mut padded = p.clone();
+        padded.coeffs.resize(20, Fr::zero());
+        // Trailing zeros are neither written nor kept when reading.
+        assert_eq!(padded.to_bytes_le().unwrap(), p.to_bytes_le().unwrap());
+
+        let mut bytes = (20u64).to_bytes_le().unwrap();
+        padded.coeffs.iter().for_each(|coeff| bytes.extend(coeff.to_bytes_le().unwrap()));
+        let candidate = DensePolynomial::<Fr>::read_le(&bytes[..]).unwrap();
+        assert_eq!(candidate.coeffs.len(), 11);
+        assert_eq!(candidate.degree(), 10);
+        assert_eq!(candidate, p);
+    }
+
+    #[test]
+    fn bytes_length_bomb() {
+        let coeff = Fr::rand(&mut thread_rng());
+        for num_coeffs in [MAX_NUM_SERIALIZED_ELEMENTS, MAX_NUM_SERIALIZED_ELEMENTS + 1, u64::MAX] {
+            let mut bytes = num_coeffs.to_bytes_le().unwrap();
+            bytes.extend(coeff.to_bytes_le().unwrap());
+            // The length prefix is not trusted, so this fails without allocating the claimed length.
+            assert!(DensePolynomial::<Fr>::read_le(&bytes[..]).is_err());
+        }
+    }
+
+    /// Asserts that `p` has no trailing zeros, and that its degree is the index of its last non-zero coefficient.
+    fn assert_normalized(p: &DensePolynomial<Fr>) {
+        assert!(p.coeffs.last().map_or(true, |coeff| !coeff.is_zero()));
+        assert_eq!(p.degree(), p.coeffs.iter().rposition(|coeff| !coeff.is_zero()).unwrap_or(0));
+    }
+
+    #[test]
+    fn arithmetic_preserves_normalization() {
+        let rng = &mut thread_rng();
+        let mut p = DensePolynomial::<Fr>::rand(20, rng);
+        for _ in 0..1000 {
+            let q = DensePolynomial::<Fr>::rand(rng.gen_range(0..30), rng);
+            // Sample an operand whose leading coefficients cancel with those of `p`.
+            let mut cancelling = -p.clone();
+            cancelling.coeffs[..p.coeffs.len() / 2].iter_mut().for_each(|coeff| *coeff = Fr::rand(rng));
+            cancelling.truncate_leading_zeros();
+
+            p = match rng.gen_range(0..10) {
+                0 => &p + &q,
+                1 => &p + &cancelling,
+                2 => &p - &q,
+                3 => &p - &(-cancelling),
+                4 => {
+                    p += &cancelling;
+                    p
+                }
+                5 => {
+                    p -= &(-cancelling);
+                    p
+                }
+                6 => {
+                    p += (Fr::rand(rng), &q);
+                    p
+                }
+                7 => &(p * Fr::zero()) + &q,
+                8 => [p, cancelling, q].into_iter().sum(),
+                _ => &(&p * &q) + &q,
+            };
+            assert_normalized(&p);
+            if p.degree() > 100 {
+                p = DensePolynomial::rand(20, rng);
+            }
+        }
+    }
+
+    #[test]
+    fn truncate_leading_zeros() {
+        let rng = &mut thread_rng();
+        let p = DensePolynomial::<Fr>::rand(10, rng);
+        let mut padded = p.clone();
+        padded.coeffs.resize(20, Fr::zero());
+        padded.truncate_leading_zeros();
+        assert_eq!(padded, p);
+        assert_normalized(&padded);
+
+        let mut zero = DensePolynomial::<Fr> { coeffs: vec![Fr::zero(); 5] };
+        assert_eq!(zero.degree(), 0);
+        zero.truncate_leading_zeros();
+        assert!(zero.coeffs.is_empty());
+        assert_normalized(&zero);
+    }
+
+    #[test]
+    fn add_assign_scaled_random() {
+        let rng = &mut thread_rng();
+        for degree in 0..70 {
+            let mut p = DensePolynomial::<Fr>::rand(degree, rng);
+            let q = DensePolynomial::<Fr>::rand(rng.gen_range(0..70), rng);
+            let scalar = Fr::rand(rng);
+            let expected = &p + &(&q * scalar);
+            p.add_assign_scaled(&q, scalar);
+            assert_eq!(p, expected);
+        }
+    }
 }
@@ -0,0 +1,115 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Polynomial multiplication without FFTs, for fields which lack a large enough two-adic subgroup.
+
+use snarkvm_fields::Field;
+
+/// The maximum length of the shorter operand for which products are computed by schoolbook multiplication.
+const MAX_NUM_COEFFS_FOR_SCHOOLBOOK: usize = 1 << 5;
+
+/// Returns the coefficients of the product of the polynomials with coefficients `a` and `b`,
+/// using Karatsuba multiplication, which takes `O(n^{log_2 3})` field operations.
+///
+/// If either operand is empty, the product is empty. Otherwise, it has `a.len() + b.len() - 1` coefficients.
+pub(crate) fn karatsuba_mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut result = vec![F::zero(); a.len() + b.len() - 1];
+    add_product(&mut result, a, b);
+    result
+}
+
+/// Adds the product of `a` and `b` to `result`, which must have at least `a.len() + b.len() - 1` coefficients.
+fn add_product<F: Field>(result: &mut [F], a: &[F], b: &[F]) {
+    // Ensure that `a` is the longer operand.
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if b.is_empty() {
+        return;
+    }
+
+    if b.len() <= MAX_NUM_COEFFS_FOR_SCHOOLBOOK {
+        for (i, a_coeff) in a.iter().enumerate() {
+            result[i..].iter_mut().zip(b).for_each(|(r, b_coeff)| *r += *a_coeff * b_coeff);
+        }
+    } else if 2 * b.len() <= a.len() {
+        // For unbalanced operands, multiply `b` by each chunk of `a` of the same length.
+        for (i, chunk) in a.chunks(b.len()).enumerate() {
+            add_product(&mut result[i * b.len()..], chunk, b);
+        }
+    } else {
+        // Split `a = a_0 + X^m a_1` and `b = b_0 + X^m b_1`, and use
+        // `a_0 b_1 + a_1 b_0 = (a_0 + a_1)(b_0 + b_1) - a_0 b_0 - a_1 b_1`.
+        let m = a.len() / 2;
+        let (a_0, a_1) = a.split_at(m);
+        let (b_0, b_1) = b.split_at(m);
+
+        let low = karatsuba_mul(a_0, b_0);
+        let high = karatsuba_mul(a_1, b_1);
+        let mut middle = karatsuba_mul(&sum(a_0, a_1), &sum(b_0, b_1));
+        middle.iter_mut().zip(&low).for_each(|(c, l)| *c -= l);
+        middle.iter_mut().zip(&high).for_each(|(c, h)| *c -= h);
+
+        result.iter_mut().zip(&low).for_each(|(r, l)| *r += l);
+        result[m..].iter_mut().zip(&middle).for_each(|(r, c)| *r += c);
+        result[2 * m..].iter_mut().zip(&high).for_each(|(r, h)| *r += h);
+    }
+}
+
+/// Returns the coefficients of the sum of the polynomials with coefficients `a` and `b`.
+fn sum<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let mut result = a.to_vec();
+    result.iter_mut().zip(b).for_each(|(r, b_coeff)| *r += b_coeff);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::DensePolynomial;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::Zero;
+
+    use rand::thread_rng;
+
+    /// Returns the product of `a` and `b` by schoolbook multiplication.
+    fn naive_mul(a: &[Fr], b: &[Fr]) -> Vec<Fr> {
+        if a.is_empty() || b.is_empty() {
+            return vec![];
+        }
+        let mut result = vec![Fr::zero(); a.len() + b.len() - 1];
+        for (i, a_coeff) in a.iter().enumerate() {
+            for (j, b_coeff) in b.iter().enumerate() {
+                result[i + j] += *a_coeff * b_coeff;
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_karatsuba_mul() {
+        let rng = &mut thread_rng();
+        for a_len in [0, 1, 2, 31, 32, 33, 64, 100, 257] {
+            for b_len in [0, 1, 32, 33, 50, 100, 300] {
+                let a = DensePolynomial::<Fr>::rand(a_len, rng).coeffs;
+                let b = DensePolynomial::<Fr>::rand(b_len, rng).coeffs;
+                assert_eq!(karatsuba_mul(&a[..a_len], &b[..b_len]), naive_mul(&a[..a_len], &b[..b_len]));
+            }
+        }
+    }
+}
@@ -20,7 +20,11 @@ use crate::fft::{EvaluationDomain, Evaluations};
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{cfg_iter_mut, serialize::*, SerializationError};
 
-use std::{borrow::Cow, convert::TryInto};
+use std::{
+    borrow::Cow,
+    convert::TryInto,
+    ops::{Add, Mul, Neg, Sub},
+};
 
 use Polynomial::*;
 
@@ -28,7 +32,8 @@ use Polynomial::*;
 use rayon::prelude::*;
 
 mod dense;
-pub use dense::DensePolynomial;
+pub use dense::{DensePolynomial, MAX_NUM_SERIALIZED_ELEMENTS};
+pub(crate) use dense::{read_field_elements, write_field_elements};
 
 mod sparse;
 pub use sparse::SparsePolynomial;
@@ -36,6 +41,11 @@ pub use sparse::SparsePolynomial;
 mod multiplier;
 pub use multiplier::*;
 
+mod karatsuba;
+
+mod subproduct_tree;
+pub use subproduct_tree::SubproductTree;
+
 /// Represents either a sparse polynomial or a dense one.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Polynomial<'a, F: Field> {
@@ -177,6 +187,24 @@ impl<'a, F: Field> Polynomial<'a, F> {
         }
     }
 
+    /// Returns `self` as a sparse polynomial, if it has at most `max_num_terms` non-zero coefficients.
+    #[inline]
+    pub fn to_sparse(&self, max_num_terms: usize) -> Option<Cow<'_, SparsePolynomial<F>>> {
+        match self {
+            Sparse(p) => match p.coeffs().count() <= max_num_terms {
+                true => Some(Cow::Borrowed(p.as_ref())),
+                false => None,
+            },
+            Dense(p) => {
+                let terms = p.coeffs.iter().enumerate().filter(|(_, coeff)| !coeff.is_zero());
+                match terms.clone().count() <= max_num_terms {
+                    true => Some(Cow::Owned(SparsePolynomial::from_coefficients(terms.map(|(i, c)| (i, *c))))),
+                    false => None,
+                }
+            }
+        }
+    }
+
     #[inline]
     pub fn as_dense_mut(&mut self) -> Option<&mut DensePolynomial<F>> {
         match self {
@@ -297,3 +325,154 @@ impl<F: PrimeField> Polynomial<'_, F> {
         }
     }
 }
+
+/// A product of sparse polynomials stays sparse if at most `1 / MIN_SPARSITY` of its coefficients are non-zero.
+const MIN_SPARSITY: usize = 4;
+
+/// The maximum number of terms in a sparse polynomial for which multiplication by a dense polynomial
+/// is done by shifting and adding, rather than by an FFT.
+const MAX_NUM_TERMS_FOR_SHIFTED_MUL: usize = 8;
+
+impl<'a, 'b, 'c, 'd, F: PrimeField> Add<&'c Polynomial<'d, F>> for &'a Polynomial<'b, F> {
+    type Output = Polynomial<'static, F>;
+
+    /// Adds `self` and `other`. The sum is sparse if both `self` and `other` are sparse.
+    fn add(self, other: &'c Polynomial<'d, F>) -> Self::Output {
+        match (self, other) {
+            (Sparse(a), Sparse(b)) => {
+                let mut result = a.clone().into_owned();
+                result += b.as_ref();
+                Sparse(Cow::Owned(result))
+            }
+            (Dense(a), Sparse(b)) | (Sparse(b), Dense(a)) => {
+                let mut result = a.clone().into_owned();
+                result += b.as_ref();
+                Dense(Cow::Owned(result))
+            }
+            (Dense(a), Dense(b)) => Dense(Cow::Owned(a.as_ref() + b.as_ref())),
+        }
+    }
+}
+
+impl<'a, F: PrimeField> Neg for &Polynomial<'a, F> {
+    type Output = Polynomial<'static, F>;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Sparse(p) => Sparse(Cow::Owned(p.as_ref() * -F::one())),
+            Dense(p) => Dense(Cow::Owned(-p.clone().into_owned())),
+        }
+    }
+}
+
+impl<'a, 'b, 'c, 'd, F: PrimeField> Sub<&'c Polynomial<'d, F>> for &'a Polynomial<'b, F> {
+    type Output = Polynomial<'static, F>;
+
+    /// Subtracts `other` from `self`. The difference is sparse if both `self` and `other` are sparse.
+    fn sub(self, other: &'c Polynomial<'d, F>) -> Self::Output {
+        self + &(-other)
+    }
+}
+
+impl<'a, 'b, 'c, 'd, F: PrimeField> Mul<&'c Polynomial<'d, F>> for &'a Polynomial<'b, F> {
+    type Output = Polynomial<'static, F>;
+
+    /// Multiplies `self` and `other`. The product of sparse polynomials is sparse,
+    /// unless more than `1 / MIN_SPARSITY` of its coefficients are non-zero.
+    fn mul(self, other: &'c Polynomial<'d, F>) -> Self::Output {
+        match (self, other) {
+            (Sparse(a), Sparse(b)) => {
+                let product = SparsePolynomial::mul(a, b);
+                match product.coeffs().count() * MIN_SPARSITY <= product.degree() + 1 {
+                    true => Sparse(Cow::Owned(product)),
+                    false => Dense(Cow::Owned(product.into())),
+                }
+            }
+            (Dense(a), Sparse(b)) | (Sparse(b), Dense(a)) => {
+                if a.is_zero() || b.is_zero() {
+                    return Dense(Cow::Owned(DensePolynomial::zero()));
+                }
+                if b.coeffs().count() > MAX_NUM_TERMS_FOR_SHIFTED_MUL {
+                    let b: DensePolynomial<F> = b.clone().into_owned().into();
+                    return Dense(Cow::Owned(a.as_ref() * &b));
+                }
+                // Add the shifted and scaled copies of `a` for each term of `b`.
+                let mut result = vec![F::zero(); a.degree() + b.degree() + 1];
+                for (shift, coeff) in b.coeffs() {
+                    cfg_iter_mut!(result[*shift..]).zip(&a.coeffs).for_each(|(r, c)| *r += *c * coeff);
+                }
+                Dense(Cow::Owned(DensePolynomial::from_coefficients_vec(result)))
+            }
+            (Dense(a), Dense(b)) => Dense(Cow::Owned(a.as_ref() * b.as_ref())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::rand::Uniform;
+
+    use rand::{thread_rng, Rng};
+
+    /// Samples a sparse polynomial with `num_terms` terms of degree at most `max_degree`.
+    fn sparse_polynomial<R: Rng>(num_terms: usize, max_degree: usize, rng: &mut R) -> SparsePolynomial<Fr> {
+        SparsePolynomial::from_coefficients((0..num_terms).map(|_| (rng.gen_range(0..=max_degree), Fr::rand(rng))))
+    }
+
+    #[test]
+    fn test_mixed_arithmetic() {
+        let rng = &mut thread_rng();
+        for _ in 0..20 {
+            let dense = DensePolynomial::<Fr>::rand(rng.gen_range(0..100), rng);
+            let sparse_polynomials = [
+                SparsePolynomial::zero(),
+                sparse_polynomial(1, 100, rng),
+                sparse_polynomial(3, 200, rng),
+                sparse_polynomial(50, 100, rng),
+            ];
+            let mut polynomials = vec![Polynomial::from(&dense), Polynomial::from(DensePolynomial::zero())];
+            polynomials.extend(sparse_polynomials.iter().map(Polynomial::from));
+
+            for a in &polynomials {
+                for b in &polynomials {
+                    let (dense_a, dense_b) = (a.to_dense(), b.to_dense());
+                    assert_eq!((a + b).to_dense().as_ref(), &(dense_a.as_ref() + dense_b.as_ref()));
+                    assert_eq!((a - b).to_dense().as_ref(), &(dense_a.as_ref() - dense_b.as_ref()));
+                    assert_eq!((a * b).to_dense().as_ref(), &(dense_a.as_ref() * dense_b.as_ref()));
+                    assert_eq!((-a).to_dense().into_owned(), -dense_a.into_owned());
+
+                    // Operations on sparse polynomials stay sparse.
+                    if let (Sparse(_), Sparse(_)) = (a, b) {
+                        assert!((a + b).as_sparse().is_some());
+                        assert!((a - b).as_sparse().is_some());
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sparse_product_density() {
+        let rng = &mut thread_rng();
+        // The product of two binomials with far apart terms stays sparse.
+        let a = Polynomial::from(SparsePolynomial::from_coefficients([(0, Fr::rand(rng)), (100, Fr::rand(rng))]));
+        assert!((&a * &a).as_sparse().is_some());
+        // The product of dense enough sparse polynomials is dense.
+        let b = Polynomial::from(SparsePolynomial::from_coefficients((0..10).map(|i| (i, Fr::rand(rng)))));
+        assert!((&b * &b).as_dense().is_some());
+    }
+
+    #[test]
+    fn test_to_sparse() {
+        let rng = &mut thread_rng();
+        let sparse = SparsePolynomial::from_coefficients([0, 7, 20, 50, 100].map(|i| (i, Fr::rand(rng))));
+        let dense = Polynomial::from(DensePolynomial::from(sparse.clone()));
+        assert_eq!(dense.to_sparse(5).unwrap().as_ref(), &sparse);
+        assert!(dense.to_sparse(4).is_none());
+        assert_eq!(Polynomial::from(&sparse).to_sparse(5).unwrap().as_ref(), &sparse);
+        assert!(Polynomial::from(&sparse).to_sparse(4).is_none());
+        assert!(Polynomial::from(&sparse).to_sparse(0).is_none());
+    }
+}
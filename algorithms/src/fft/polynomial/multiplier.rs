@@ -16,11 +16,14 @@
 
 use std::{borrow::Borrow, collections::BTreeMap};
 
-use crate::fft::domain::{FFTPrecomputation, IFFTPrecomputation};
+use crate::fft::{
+    domain::{FFTPrecomputation, IFFTPrecomputation},
+    parallel::mul_assign_pointwise,
+};
 
 /// A struct that helps multiply a batch of polynomials
 use super::*;
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, ExecutionPool};
+use snarkvm_utilities::{cfg_iter, ExecutionPool};
 
 #[derive(Default)]
 pub struct PolyMultiplier<'a, F: PrimeField> {
@@ -117,7 +120,7 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
                 let mut result = results
                     .into_par_iter()
                     .reduce_with(|mut a, b| {
-                        cfg_iter_mut!(a).zip(b).for_each(|(a, b)| *a *= b);
+                        mul_assign_pointwise(&mut a, &b);
                         a
                     })
                     .unwrap();
@@ -125,7 +128,7 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
                 let mut result = results
                     .into_iter()
                     .reduce(|mut a, b| {
-                        cfg_iter_mut!(a).zip(b).for_each(|(a, b)| *a *= b);
+                        mul_assign_pointwise(&mut a, &b);
                         a
                     })
                     .unwrap();
@@ -179,3 +182,89 @@ impl<'a, F: PrimeField> PolyMultiplier<'a, F> {
         Some(DensePolynomial::from_coefficients_vec(result))
     }
 }
+
+/// Reusable buffers for multiplying polynomials over a fixed domain.
+///
+/// After the first multiplication, `DensePolynomial::mul_in_place_with_scratch` reuses
+/// the buffers in `self` and does not allocate any domain-sized vectors.
+#[derive(Clone, Debug)]
+pub struct MulScratch<F: PrimeField> {
+    domain: EvaluationDomain<F>,
+    fft_precomputation: FFTPrecomputation<F>,
+    ifft_precomputation: IFFTPrecomputation<F>,
+    lhs: Vec<F>,
+    rhs: Vec<F>,
+}
+
+impl<F: PrimeField> MulScratch<F> {
+    /// Initializes scratch space for products with up to `num_coeffs` coefficients.
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        let domain = EvaluationDomain::new(num_coeffs)?;
+        let fft_precomputation = domain.precompute_fft();
+        let ifft_precomputation = fft_precomputation.to_ifft_precomputation();
+        Some(Self {
+            domain,
+            fft_precomputation,
+            ifft_precomputation,
+            lhs: Vec::with_capacity(domain.size()),
+            rhs: Vec::with_capacity(domain.size()),
+        })
+    }
+
+    /// Returns the domain over which the products are computed.
+    pub fn domain(&self) -> EvaluationDomain<F> {
+        self.domain
+    }
+
+    /// Returns the coefficients of `lhs * rhs`, or `None` if the product does not fit in the domain.
+    pub(super) fn multiply(&mut self, lhs: &DensePolynomial<F>, rhs: &DensePolynomial<F>) -> Option<&[F]> {
+        if lhs.is_zero() || rhs.is_zero() {
+            return Some(&[]);
+        }
+        let num_coeffs = lhs.degree() + rhs.degree() + 1;
+        if num_coeffs > self.domain.size() {
+            return None;
+        }
+
+        let Self { domain, fft_precomputation, ifft_precomputation, lhs: lhs_evals, rhs: rhs_evals } = self;
+        for (evals, polynomial) in [(&mut *lhs_evals, lhs), (&mut *rhs_evals, rhs)] {
+            evals.clear();
+            evals.extend_from_slice(&polynomial.coeffs);
+            evals.resize(domain.size(), F::zero());
+            domain.deranged_fft_in_place_with_pc(evals, fft_precomputation);
+        }
+        mul_assign_pointwise(lhs_evals, rhs_evals);
+        domain.deranged_ifft_in_place_with_pc(lhs_evals, ifft_precomputation);
+        Some(&lhs_evals[..num_coeffs])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_mul_with_scratch() {
+        let rng = &mut thread_rng();
+        let mut scratch = MulScratch::<Fr>::new(128).unwrap();
+        for a_degree in 0..64 {
+            for b_degree in 0..64 {
+                let a = DensePolynomial::<Fr>::rand(a_degree, rng);
+                let b = DensePolynomial::<Fr>::rand(b_degree, rng);
+                let product = a.mul_in_place_with_scratch(&b, &mut scratch).unwrap();
+                assert_eq!(product, (&a * &b).coeffs());
+            }
+        }
+
+        // The product of the zero polynomial is the zero polynomial.
+        let a = DensePolynomial::<Fr>::rand(10, rng);
+        assert!(a.mul_in_place_with_scratch(&DensePolynomial::zero(), &mut scratch).unwrap().is_empty());
+
+        // Products that do not fit in the domain are rejected.
+        let a = DensePolynomial::<Fr>::rand(64, rng);
+        assert!(a.mul_in_place_with_scratch(&a, &mut scratch).is_none());
+    }
+}
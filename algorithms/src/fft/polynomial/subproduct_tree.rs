@@ -0,0 +1,260 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_fields::batch_inversion;
+use snarkvm_utilities::{cfg_chunks, cfg_iter};
+
+/// The degree at or below which remainders are computed by long division.
+const MAX_DEGREE_FOR_LONG_DIVISION: usize = 1 << 6;
+
+/// A subproduct tree over a list of points `x_0, ..., x_{n-1}`.
+/// The leaves are the linear polynomials `X - x_i`, and every other node is the product of its children.
+///
+/// The tree allows evaluating a polynomial at all of the points, and interpolating a polynomial
+/// through all of the points, in `O(n log^2 n)` field operations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubproductTree<F: PrimeField> {
+    /// The points at the leaves of the tree.
+    points: Vec<F>,
+    /// The layers of the tree, from the leaves to the root.
+    /// The parent of the `i`-th node in a layer is the `(i / 2)`-th node of the next layer.
+    layers: Vec<Vec<DensePolynomial<F>>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    /// Initializes the subproduct tree over the given points.
+    pub fn new(points: &[F]) -> Self {
+        let leaves = cfg_iter!(points)
+            .map(|point| DensePolynomial::from_coefficients_vec(vec![-*point, F::one()]))
+            .collect::<Vec<_>>();
+
+        let mut layers = vec![leaves];
+        while layers.last().map_or(false, |layer| layer.len() > 1) {
+            let layer = cfg_chunks!(layers.last().unwrap(), 2)
+                .map(|nodes| match nodes {
+                    [left, right] => left * right,
+                    [node] => node.clone(),
+                    _ => unreachable!("chunks contain one or two nodes"),
+                })
+                .collect();
+            layers.push(layer);
+        }
+        Self { points: points.to_vec(), layers }
+    }
+
+    /// Returns the points at the leaves of the tree.
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// Returns the vanishing polynomial of the points, i.e. the product of all `X - x_i`.
+    pub fn vanishing_polynomial(&self) -> DensePolynomial<F> {
+        match self.layers.last().and_then(|layer| layer.first()) {
+            Some(root) => root.clone(),
+            None => DensePolynomial::from_coefficients_vec(vec![F::one()]),
+        }
+    }
+
+    /// Returns the evaluations of `polynomial` at each of the points.
+    pub fn evaluate(&self, polynomial: &DensePolynomial<F>) -> Vec<F> {
+        let (leaves, ancestors) = match self.layers.split_first() {
+            Some((leaves, ancestors)) if !leaves.is_empty() => (leaves, ancestors),
+            _ => return vec![],
+        };
+
+        // Reduce the polynomial modulo each node, from the root down to the parents of the leaves.
+        let mut remainders = vec![polynomial.clone()];
+        for layer in ancestors.iter().rev() {
+            remainders =
+                cfg_iter!(layer).enumerate().map(|(i, node)| remainder(&remainders[i / 2], node)).collect::<Vec<_>>();
+        }
+
+        // The remainders now have degree at most one, so evaluate them directly.
+        debug_assert_eq!(leaves.len(), self.points.len());
+        cfg_iter!(self.points).enumerate().map(|(i, point)| remainders[i / 2].evaluate(*point)).collect()
+    }
+
+    /// Returns the unique polynomial of degree less than the number of points,
+    /// which takes the value `values[i]` at the `i`-th point.
+    ///
+    /// Returns `None` if the number of values does not match the number of points,
+    /// or if the points are not distinct.
+    pub fn interpolate(&self, values: &[F]) -> Option<DensePolynomial<F>> {
+        if values.len() != self.points.len() {
+            return None;
+        }
+        if values.is_empty() {
+            return Some(DensePolynomial::zero());
+        }
+
+        // The Lagrange basis polynomial for `x_i` is `M(X) / ((X - x_i) M'(x_i))`,
+        // where `M` is the vanishing polynomial of the points.
+        let vanishing_polynomial = self.vanishing_polynomial();
+        let derivative = vanishing_polynomial.coeffs.iter().enumerate().skip(1).map(|(i, c)| F::from(i as u64) * c);
+        let derivative = DensePolynomial::from_coefficients_vec(derivative.collect());
+        let mut weights = self.evaluate(&derivative);
+        // The derivative vanishes at a point if and only if the point is repeated.
+        if weights.iter().any(|weight| weight.is_zero()) {
+            return None;
+        }
+        batch_inversion(&mut weights);
+
+        // Combine the weighted values from the leaves up to the root.
+        let mut combinations = cfg_iter!(weights)
+            .zip(values)
+            .map(|(weight, value)| DensePolynomial::from_coefficients_vec(vec![*weight * value]))
+            .collect::<Vec<_>>();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            combinations = cfg_chunks!(combinations, 2)
+                .zip(cfg_chunks!(layer, 2))
+                .map(|(combinations, nodes)| match (combinations, nodes) {
+                    ([left, right], [left_node, right_node]) => &(left * right_node) + &(right * left_node),
+                    ([combination], [_]) => combination.clone(),
+                    _ => unreachable!("chunks contain one or two nodes"),
+                })
+                .collect();
+        }
+        combinations.pop()
+    }
+}
+
+/// Returns the remainder of `dividend` modulo `divisor`.
+///
+/// For large divisors, the quotient is computed from the inverse of the reversed divisor,
+/// which is found by Newton iteration, so that the division takes `O(n log n)` field operations.
+fn remainder<F: PrimeField>(dividend: &DensePolynomial<F>, divisor: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if dividend.is_zero() || dividend.degree() < divisor.degree() {
+        return dividend.clone();
+    }
+    if divisor.degree() <= MAX_DEGREE_FOR_LONG_DIVISION {
+        let (_, remainder) = Polynomial::from(dividend).divide_with_q_and_r(&divisor.into()).unwrap();
+        return remainder;
+    }
+
+    // If `rev_k(p) = X^k p(1 / X)`, then `rev(dividend) = rev(quotient) * rev(divisor) mod X^{m + 1}`,
+    // where `m` is the degree of the quotient.
+    let m = dividend.degree() - divisor.degree();
+    let reversed_divisor = reverse(divisor, m + 1);
+    let reversed_quotient =
+        truncate(&(&reverse(dividend, m + 1) * &inverse_mod_x_power(&reversed_divisor, m + 1)), m + 1);
+
+    let mut quotient = reversed_quotient.coeffs;
+    quotient.resize(m + 1, F::zero());
+    quotient.reverse();
+    let quotient = DensePolynomial::from_coefficients_vec(quotient);
+
+    let remainder = dividend - &(&quotient * divisor);
+    debug_assert!(remainder.is_zero() || remainder.degree() < divisor.degree());
+    remainder
+}
+
+/// Returns the first `num_coeffs` coefficients of the reversal of `polynomial`.
+fn reverse<F: PrimeField>(polynomial: &DensePolynomial<F>, num_coeffs: usize) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_vec(polynomial.coeffs.iter().rev().take(num_coeffs).copied().collect())
+}
+
+/// Returns `polynomial mod X^num_coeffs`.
+fn truncate<F: PrimeField>(polynomial: &DensePolynomial<F>, num_coeffs: usize) -> DensePolynomial<F> {
+    DensePolynomial::from_coefficients_slice(&polynomial.coeffs[..num_coeffs.min(polynomial.coeffs.len())])
+}
+
+/// Returns the inverse of `polynomial mod X^num_coeffs`, which exists if the constant term is non-zero.
+fn inverse_mod_x_power<F: PrimeField>(polynomial: &DensePolynomial<F>, num_coeffs: usize) -> DensePolynomial<F> {
+    let constant_inverse = polynomial.coeffs[0].inverse().expect("the constant term must be non-zero");
+    let mut inverse = DensePolynomial::from_coefficients_vec(vec![constant_inverse]);
+    let mut precision = 1;
+    while precision < num_coeffs {
+        precision = (2 * precision).min(num_coeffs);
+        // Each iteration doubles the precision, as `inverse * (2 - polynomial * inverse) = 1 mod X^{2k}`
+        // whenever `inverse * polynomial = 1 mod X^k`.
+        let mut correction = -truncate(&(&truncate(polynomial, precision) * &inverse), precision);
+        correction.coeffs[0] += F::one() + F::one();
+        inverse = truncate(&(&inverse * &correction), precision);
+    }
+    inverse
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::Uniform;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_remainder() {
+        let rng = &mut thread_rng();
+        for divisor_degree in [1, 10, MAX_DEGREE_FOR_LONG_DIVISION + 1, 100, 200] {
+            for dividend_degree in [0, divisor_degree - 1, divisor_degree, 2 * divisor_degree, 300] {
+                let dividend = DensePolynomial::<Fr>::rand(dividend_degree, rng);
+                let divisor = DensePolynomial::<Fr>::rand(divisor_degree, rng);
+                let (_, expected) = Polynomial::from(&dividend).divide_with_q_and_r(&(&divisor).into()).unwrap();
+                assert_eq!(remainder(&dividend, &divisor), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate_and_interpolate() {
+        let rng = &mut thread_rng();
+        for num_points in [1, 2, 3, 17, 64, 100, 257] {
+            let points = (0..num_points).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+            let tree = SubproductTree::new(&points);
+
+            let vanishing_polynomial = tree.vanishing_polynomial();
+            assert_eq!(vanishing_polynomial.degree(), num_points);
+            assert!(points.iter().all(|point| vanishing_polynomial.evaluate(*point).is_zero()));
+
+            for degree in [0, 1, num_points - 1, num_points, 3 * num_points] {
+                let polynomial = DensePolynomial::<Fr>::rand(degree, rng);
+                let expected = points.iter().map(|point| polynomial.evaluate(*point)).collect::<Vec<_>>();
+                let evaluations = tree.evaluate(&polynomial);
+                assert_eq!(evaluations, expected);
+
+                if degree < num_points {
+                    assert_eq!(tree.interpolate(&evaluations).unwrap(), polynomial);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_repeated_points() {
+        let rng = &mut thread_rng();
+        let mut points = (0..100).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        points[42] = points[7];
+        let tree = SubproductTree::new(&points);
+
+        let polynomial = DensePolynomial::<Fr>::rand(150, rng);
+        let expected = points.iter().map(|point| polynomial.evaluate(*point)).collect::<Vec<_>>();
+        assert_eq!(tree.evaluate(&polynomial), expected);
+
+        // Interpolation is not possible through repeated points.
+        assert!(tree.interpolate(&expected).is_none());
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree = SubproductTree::<Fr>::new(&[]);
+        let polynomial = DensePolynomial::<Fr>::rand(10, &mut thread_rng());
+        assert!(tree.evaluate(&polynomial).is_empty());
+        assert_eq!(tree.interpolate(&[]).unwrap(), DensePolynomial::zero());
+        assert!(tree.interpolate(&[Fr::one()]).is_none());
+    }
+}
@@ -42,16 +42,19 @@ pub enum PCError {
 
     /// The degree of the polynomial passed to `commit` or `open`
     /// was too large.
-    TooManyCoefficients {
-        /// The number of coefficients in the polynomial.
-        num_coefficients: usize,
-        /// The maximum number of powers provided in `Powers`.
-        num_powers: usize,
+    PolynomialDegreeTooLarge {
+        /// The degree of the polynomial.
+        degree: usize,
+        /// The maximum degree supported by the provided `Powers`.
+        supported_degree: usize,
     },
 
     /// The hiding bound was not `None`, but the hiding bound was zero.
     HidingBoundIsZero,
 
+    /// The proof was hiding, but the verifying key has no hiding generator to check it against.
+    HidingProofForNonHidingKey,
+
     /// The hiding bound was too large for the given `Powers`.
     HidingBoundToolarge {
         /// The hiding bound
@@ -105,6 +108,12 @@ impl From<anyhow::Error> for PCError {
     }
 }
 
+impl From<crate::fft::DomainError> for PCError {
+    fn from(other: crate::fft::DomainError) -> Self {
+        Self::AnyhowError(other.into())
+    }
+}
+
 impl core::fmt::Display for PCError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -119,13 +128,15 @@ impl core::fmt::Display for PCError {
             ),
             Self::MissingRng => write!(f, "hiding commitments require `Some(rng)`"),
             Self::DegreeIsZero => write!(f, "this scheme does not support committing to degree 0 polynomials"),
-            Self::TooManyCoefficients { num_coefficients, num_powers } => write!(
+            Self::PolynomialDegreeTooLarge { degree, supported_degree } => write!(
                 f,
-                "the number of coefficients in the polynomial ({:?}) is greater than\
-                 the maximum number of powers in `Powers` ({:?})",
-                num_coefficients, num_powers
+                "the degree of the polynomial ({:?}) is greater than the maximum degree supported by `Powers` ({:?})",
+                degree, supported_degree
             ),
             Self::HidingBoundIsZero => write!(f, "this scheme does not support non-`None` hiding bounds that are 0"),
+            Self::HidingProofForNonHidingKey => {
+                write!(f, "the proof is hiding, but the verifying key does not support hiding proofs")
+            }
             Self::HidingBoundToolarge { hiding_poly_degree, num_powers } => write!(
                 f,
                 "the degree of the hiding poly ({:?}) is not less than the maximum number of powers in `Powers` ({:?})",
@@ -23,9 +23,13 @@ use snarkvm_fields::{ConstraintFieldError, PrimeField, ToConstraintField, Zero};
 use snarkvm_parameters::testnet3::PowersOfG;
 use snarkvm_utilities::{
     borrow::Cow,
+    cfg_into_iter,
+    cfg_iter,
     error,
     io::{Read, Write},
-    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    rand::Uniform,
+    read_vec_bounded,
+    serialize::{CanonicalBytes, CanonicalDeserialize, CanonicalSerialize, Compress, Validate},
     FromBytes,
     ToBytes,
     ToMinimalBits,
@@ -33,10 +37,14 @@ use snarkvm_utilities::{
 
 use anyhow::Result;
 use core::ops::{Add, AddAssign};
+use itertools::Itertools;
 use parking_lot::RwLock;
 use rand_core::RngCore;
 use std::{collections::BTreeMap, io, ops::Range, sync::Arc};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// `UniversalParams` are the universal parameters for the KZG10 scheme.
 #[derive(Clone, Debug)]
 pub struct UniversalParams<E: PairingEngine> {
@@ -53,6 +61,13 @@ pub struct UniversalParams<E: PairingEngine> {
     pub prepared_h: <E::G2Affine as PairingCurve>::Prepared,
     /// \beta times the above generator of G2, prepared for use in pairings.
     pub prepared_beta_h: <E::G2Affine as PairingCurve>::Prepared,
+    /// The most recently computed Lagrange basis, returned by `shared_lagrange_basis` for domains of its size.
+    ///
+    /// Only one basis is kept, and it stays alive until it is replaced or cleared, even after every key
+    /// using it has been dropped. The cache is behind an `Arc`, so it is shared by all clones of `self`:
+    /// a basis computed through one clone is reused by the others, and `clear_lagrange_basis_cache`
+    /// on any clone clears it for all of them. Use `lagrange_basis` to compute a basis without the cache.
+    lagrange_basis: Arc<RwLock<Option<Arc<Vec<E::G1Affine>>>>>,
 }
 
 impl<E: PairingEngine> UniversalParams<E> {
@@ -63,7 +78,9 @@ impl<E: PairingEngine> UniversalParams<E> {
         let prepared_beta_h = powers.read().beta_h().prepare();
         let supported_degree_bounds = vec![1 << 10, 1 << 15, 1 << 20, 1 << 25, 1 << 30];
 
-        Ok(Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h })
+        let lagrange_basis = Default::default();
+
+        Ok(Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h, lagrange_basis })
     }
 
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
@@ -76,6 +93,23 @@ impl<E: PairingEngine> UniversalParams<E> {
         Ok(E::G1Projective::batch_normalization_into_affine(basis))
     }
 
+    /// Returns the Lagrange basis for `domain`, reusing the basis of the previous call if it was over a domain
+    /// of the same size. Otherwise, the basis is computed, and replaces the previous one in the cache.
+    pub fn shared_lagrange_basis(&self, domain: EvaluationDomain<E::Fr>) -> Result<Arc<Vec<E::G1Affine>>> {
+        if let Some(basis) = self.lagrange_basis.read().as_ref().filter(|basis| basis.len() == domain.size()) {
+            return Ok(basis.clone());
+        }
+        let basis = Arc::new(self.lagrange_basis(domain)?);
+        *self.lagrange_basis.write() = Some(basis.clone());
+        Ok(basis)
+    }
+
+    /// Drops the cached Lagrange basis, for `self` and all of its clones.
+    /// Keys that were trimmed with the basis keep their own reference to it.
+    pub fn clear_lagrange_basis_cache(&self) {
+        *self.lagrange_basis.write() = None;
+    }
+
     pub fn power_of_beta_g(&self, which_power: usize) -> Result<E::G1Affine> {
         self.powers.write().power_of_beta_g(which_power)
     }
@@ -103,6 +137,12 @@ impl<E: PairingEngine> UniversalParams<E> {
     pub fn supported_degree_bounds(&self) -> &[usize] {
         &self.supported_degree_bounds
     }
+
+    /// Returns `true` if the parameters contain the negative power of `beta_h`
+    /// needed to verify openings enforcing the given `degree_bound`.
+    pub fn supports_degree_bound(&self, degree_bound: usize) -> bool {
+        self.neg_powers_of_beta_h().contains_key(&degree_bound)
+    }
 }
 
 impl<E: PairingEngine> FromBytes for UniversalParams<E> {
@@ -114,12 +154,12 @@ impl<E: PairingEngine> FromBytes for UniversalParams<E> {
         let h: E::G2Affine = FromBytes::read_le(&mut reader)?;
 
         // Deserialize `supported_degree_bounds`.
-        let supported_degree_bounds_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut supported_degree_bounds = Vec::with_capacity(supported_degree_bounds_len as usize);
-        for _ in 0..supported_degree_bounds_len {
-            let degree_bound: u32 = FromBytes::read_le(&mut reader)?;
-            supported_degree_bounds.push(degree_bound as usize);
-        }
+        // There is at most one degree bound per power.
+        let max_num_degree_bounds = powers.read().max_num_powers();
+        let supported_degree_bounds = read_vec_bounded::<u32, _>(&mut reader, max_num_degree_bounds)?
+            .into_iter()
+            .map(|degree_bound| degree_bound as usize)
+            .collect();
 
         // Deserialize `prepared_h`.
         let prepared_h: <E::G2Affine as PairingCurve>::Prepared = FromBytes::read_le(&mut reader)?;
@@ -127,7 +167,9 @@ impl<E: PairingEngine> FromBytes for UniversalParams<E> {
         // Deserialize `prepared_beta_h`.
         let prepared_beta_h: <E::G2Affine as PairingCurve>::Prepared = FromBytes::read_le(&mut reader)?;
 
-        Ok(Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h })
+        let lagrange_basis = Default::default();
+
+        Ok(Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h, lagrange_basis })
     }
 }
 
@@ -169,6 +211,20 @@ impl<E: PairingEngine> Powers<'_, E> {
     pub fn size(&self) -> usize {
         self.powers_of_beta_g.len()
     }
+
+    /// The maximum degree of a polynomial that can be committed to with `self`.
+    pub fn supported_degree(&self) -> usize {
+        self.size().saturating_sub(1)
+    }
+
+    /// Returns the powers `β^i G` for `i` in `lower..upper`, borrowed from `self`,
+    /// along with all of the hiding powers. Returns `None` if the range is out of bounds.
+    pub fn window(&self, lower: usize, upper: usize) -> Option<Powers<'_, E>> {
+        Some(Powers {
+            powers_of_beta_g: Cow::Borrowed(self.powers_of_beta_g.get(lower..upper)?),
+            powers_of_beta_times_gamma_g: Cow::Borrowed(&self.powers_of_beta_times_gamma_g),
+        })
+    }
 }
 /// `LagrangeBasis` is used to commit to and create evaluation proofs for a given polynomial.
 #[derive(Clone, Debug, Hash)]
@@ -206,17 +262,20 @@ pub struct VerifierKey<E: PairingEngine> {
     pub prepared_beta_h: <E::G2Affine as PairingCurve>::Prepared,
 }
 
+impl<E: PairingEngine> CanonicalBytes for VerifierKey<E> {
+    const COMPRESS: Compress = Compress::Yes;
+}
+
 impl<E: PairingEngine> FromBytes for VerifierKey<E> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
-        CanonicalDeserialize::deserialize_compressed(&mut reader)
+        Self::read_le_with(&mut reader, Self::COMPRESS, Self::VALIDATE)
             .map_err(|_| error("could not deserialize VerifierKey"))
     }
 }
 
 impl<E: PairingEngine> ToBytes for VerifierKey<E> {
     fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
-        CanonicalSerialize::serialize_compressed(self, &mut writer)
-            .map_err(|_| error("could not serialize VerifierKey"))
+        self.write_le_with(&mut writer, Self::COMPRESS).map_err(|_| error("could not serialize VerifierKey"))
     }
 }
 
@@ -275,6 +334,12 @@ impl<E: PairingEngine> PreparedVerifierKey<E> {
     }
 }
 
+impl<E: PairingEngine> From<&VerifierKey<E>> for PreparedVerifierKey<E> {
+    fn from(vk: &VerifierKey<E>) -> Self {
+        Self::prepare(vk)
+    }
+}
+
 /// `KZGCommitment` commits to a polynomial. It is output by `KZG10::commit`.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
 pub struct KZGCommitment<E: PairingEngine>(
@@ -282,17 +347,23 @@ pub struct KZGCommitment<E: PairingEngine>(
     pub E::G1Affine,
 );
 
+impl<E: PairingEngine> CanonicalBytes for KZGCommitment<E> {
+    const COMPRESS: Compress = Compress::Yes;
+}
+
 impl<E: PairingEngine> FromBytes for KZGCommitment<E> {
+    /// Reads the commitment from a compressed encoding, checking that the point is on the curve
+    /// and in the correct subgroup.
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
-        CanonicalDeserialize::deserialize_compressed(&mut reader)
+        Self::read_le_with(&mut reader, Self::COMPRESS, Self::VALIDATE)
             .map_err(|_| error("could not deserialize KZGCommitment"))
     }
 }
 
 impl<E: PairingEngine> ToBytes for KZGCommitment<E> {
+    /// Writes the commitment as a compressed point (the x-coordinate and a flag for the sign of y).
     fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
-        CanonicalSerialize::serialize_compressed(self, &mut writer)
-            .map_err(|_| error("could not serialize KZGCommitment"))
+        self.write_le_with(&mut writer, Self::COMPRESS).map_err(|_| error("could not serialize KZGCommitment"))
     }
 }
 
@@ -315,6 +386,57 @@ impl<E: PairingEngine> KZGCommitment<E> {
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
         self.0.is_in_correct_subgroup_assuming_on_curve()
     }
+
+    /// Reads the commitment from a compressed encoding, checking that the point is on the curve,
+    /// but *not* that it is in the correct subgroup. The caller must validate the commitment,
+    /// e.g. with `KZGCommitment::batch_validate`, before using it.
+    pub fn read_le_unchecked<R: Read>(mut reader: R) -> io::Result<Self> {
+        Self::read_le_with(&mut reader, Self::COMPRESS, Validate::No)
+            .map_err(|_| error("could not deserialize KZGCommitment"))
+    }
+
+    /// Checks that every commitment is in the correct subgroup, returning the index of the
+    /// first offending commitment otherwise. The commitments are assumed to be on the curve.
+    ///
+    /// A single random linear combination of the commitments is *not* a sound batch check,
+    /// as the cofactor of the curve has small prime factors (e.g. 2 and 3 for BLS12-377),
+    /// whose torsion components a random combination cancels with constant probability.
+    /// Instead, the commitments are summed over 128 random subsets, and each sum is checked.
+    /// Adding or removing a commitment outside of the subgroup changes the torsion component
+    /// of a sum, so each sum misses it with probability at most 1/2, and all of them with probability
+    /// at most 2^{-128}, whatever the cofactor. This costs an addition per commitment and subset,
+    /// rather than a scalar multiplication per commitment.
+    ///
+    /// If the batch check fails, the commitments are checked one by one to find the offending index.
+    pub fn batch_validate(commitments: &[Self]) -> Result<()> {
+        // Small batches are cheaper to check one by one than with 128 subset sums.
+        if commitments.len() > u128::BITS as usize {
+            // Commitment `i` is in subset `j` if bit `j` of `selectors[i]` is set.
+            let rng = &mut rand::thread_rng();
+            let selectors = (0..commitments.len()).map(|_| u128::rand(rng)).collect::<Vec<_>>();
+
+            let is_valid = cfg_into_iter!(0..u128::BITS).all(|j| {
+                let mut sum = E::G1Projective::zero();
+                for (commitment, selector) in commitments.iter().zip_eq(&selectors) {
+                    if (selector >> j) & 1 == 1 {
+                        sum.add_assign_mixed(&commitment.0);
+                    }
+                }
+                sum.to_affine().is_in_correct_subgroup_assuming_on_curve()
+            });
+            if is_valid {
+                return Ok(());
+            }
+        }
+
+        let is_valid = cfg_iter!(commitments)
+            .map(|commitment| commitment.is_in_correct_subgroup_assuming_on_curve())
+            .collect::<Vec<_>>();
+        match is_valid.iter().position(|is_valid| !is_valid) {
+            Some(index) => anyhow::bail!("Commitment {index} is not in the correct subgroup"),
+            None => Ok(()),
+        }
+    }
 }
 
 impl<E: PairingEngine> ToConstraintField<E::Fq> for KZGCommitment<E> {
@@ -450,15 +572,23 @@ impl<E: PairingEngine> KZGProof<E> {
     }
 }
 
+impl<E: PairingEngine> CanonicalBytes for KZGProof<E> {
+    const COMPRESS: Compress = Compress::Yes;
+}
+
 impl<E: PairingEngine> FromBytes for KZGProof<E> {
+    /// Reads the proof from a compressed encoding, checking that the point is on the curve
+    /// and in the correct subgroup.
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
-        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize KZG proof"))
+        Self::read_le_with(&mut reader, Self::COMPRESS, Self::VALIDATE)
+            .map_err(|_| error("could not deserialize KZG proof"))
     }
 }
 
 impl<E: PairingEngine> ToBytes for KZGProof<E> {
+    /// Writes the proof with a compressed point (the x-coordinate and a flag for the sign of y).
     fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
-        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize KZG proof"))
+        self.write_le_with(&mut writer, Self::COMPRESS).map_err(|_| error("could not serialize KZG proof"))
     }
 }
 
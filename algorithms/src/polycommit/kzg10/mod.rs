@@ -22,14 +22,14 @@
 //! This construction achieves extractability in the algebraic group model (AGM).
 
 use crate::{
-    fft::{DensePolynomial, Polynomial},
+    fft::{DensePolynomial, Evaluations, Polynomial},
     msm::VariableBase,
     polycommit::PCError,
 };
 use anyhow::anyhow;
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE};
+use snarkvm_utilities::{cfg_iter, cfg_iter_mut, rand::Uniform, BitIteratorBE, BitIteratorLE};
 
 use core::{
     marker::PhantomData,
@@ -47,7 +47,7 @@ pub use data_structures::*;
 
 use super::sonic_pc::LabeledPolynomialWithBasis;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum KZGDegreeBounds {
     All,
     Marlin,
@@ -81,6 +81,23 @@ impl KZGDegreeBounds {
             KZGDegreeBounds::None => vec![],
         }
     }
+
+    /// Returns the smallest configuration covering the degree bounds of both `self` and `other`,
+    /// so that a single setup can serve several consumers (e.g. Marlin and the coinbase puzzle).
+    pub fn union<F: PrimeField>(&self, other: &Self, max_degree: usize) -> Self {
+        match (self, other) {
+            (KZGDegreeBounds::All, _) | (_, KZGDegreeBounds::All) => KZGDegreeBounds::All,
+            (KZGDegreeBounds::None, bounds) | (bounds, KZGDegreeBounds::None) => bounds.clone(),
+            (KZGDegreeBounds::Marlin, KZGDegreeBounds::Marlin) => KZGDegreeBounds::Marlin,
+            _ => {
+                let mut list = self.get_list::<F>(max_degree);
+                list.extend(other.get_list::<F>(max_degree));
+                list.sort_unstable();
+                list.dedup();
+                KZGDegreeBounds::List(list)
+            }
+        }
+    }
 }
 
 /// `KZG10` is an implementation of the polynomial commitment scheme of
@@ -225,6 +242,22 @@ impl<E: PairingEngine> KZG10<E> {
         Ok((KZGCommitment(commitment.into()), randomness))
     }
 
+    /// Outputs a commitment to the polynomial whose evaluations over `lagrange_basis.domain`
+    /// are given by `evaluations`, without interpolating the polynomial into coefficient form.
+    pub fn commit_evaluations(
+        lagrange_basis: &LagrangeBasis<E>,
+        evaluations: &Evaluations<E::Fr>,
+        hiding_bound: Option<usize>,
+        terminator: &AtomicBool,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(KZGCommitment<E>, KZGRandomness<E>), PCError> {
+        // Ensure the evaluations are defined over the same domain as the Lagrange basis.
+        if evaluations.domain() != lagrange_basis.domain || evaluations.domain().size() != lagrange_basis.size() {
+            return Err(PCError::UnsupportedLagrangeBasisSize(evaluations.domain().size()));
+        }
+        Self::commit_lagrange(lagrange_basis, evaluations.evaluations(), hiding_bound, terminator, rng)
+    }
+
     /// Compute witness polynomial.
     ///
     /// The witness polynomial w(x) the quotient of the division (p(x) - p(z)) / (x - z)
@@ -236,17 +269,15 @@ impl<E: PairingEngine> KZG10<E> {
         point: E::Fr,
         randomness: &KZGRandomness<E>,
     ) -> Result<(DensePolynomial<E::Fr>, Option<DensePolynomial<E::Fr>>), PCError> {
-        let divisor = DensePolynomial::from_coefficients_vec(vec![-point, E::Fr::one()]);
-
         let witness_time = start_timer!(|| "Computing witness polynomial");
-        let witness_polynomial = polynomial / &divisor;
+        let (witness_polynomial, _) = polynomial.divide_by_linear(point);
         end_timer!(witness_time);
 
         let random_witness_polynomial = if randomness.is_hiding() {
             let random_p = &randomness.blinding_polynomial;
 
             let witness_time = start_timer!(|| "Computing random witness polynomial");
-            let random_witness_polynomial = random_p / &divisor;
+            let (random_witness_polynomial, _) = random_p.divide_by_linear(point);
             end_timer!(witness_time);
             Some(random_witness_polynomial)
         } else {
@@ -349,18 +380,63 @@ impl<E: PairingEngine> KZG10<E> {
         value: E::Fr,
         proof: &KZGProof<E>,
     ) -> Result<bool, PCError> {
+        Self::check_hiding_is_supported(vk.gamma_g.is_zero(), [proof])?;
+
         let check_time = start_timer!(|| "Checking evaluation");
         let mut inner = commitment.0.to_projective() - vk.g.to_projective().mul(value);
         if let Some(random_v) = proof.random_v {
             inner -= &vk.gamma_g.mul(random_v);
         }
-        let lhs = E::pairing(inner, vk.h);
+        let result = Self::check_pairing(inner, proof.w, point, &vk.prepared_h, &vk.prepared_beta_h);
+        end_timer!(check_time, || format!("Result: {}", result));
+        Ok(result)
+    }
 
-        let inner = vk.beta_h.to_projective() - vk.h.mul(point);
-        let rhs = E::pairing(proof.w, inner);
+    /// Verifies that `value` is the evaluation at `point` of the polynomial
+    /// committed inside `commitment`, using a verifying key that has been prepared once
+    /// and is reused across many calls.
+    pub fn check_prepared(
+        pvk: &PreparedVerifierKey<E>,
+        commitment: &KZGCommitment<E>,
+        point: E::Fr,
+        value: E::Fr,
+        proof: &KZGProof<E>,
+    ) -> Result<bool, PCError> {
+        let gamma_g_is_zero = pvk.prepared_gamma_g.first().map_or(true, |gamma_g| gamma_g.is_zero());
+        Self::check_hiding_is_supported(gamma_g_is_zero, [proof])?;
 
-        end_timer!(check_time, || format!("Result: {}", lhs == rhs));
-        Ok(lhs == rhs)
+        let check_time = start_timer!(|| "Checking evaluation with a prepared verifying key");
+        let mut inner = commitment.0.to_projective() - mul_with_doublings::<E>(&pvk.prepared_g, value);
+        if let Some(random_v) = proof.random_v {
+            inner -= &mul_with_doublings::<E>(&pvk.prepared_gamma_g, random_v);
+        }
+        let result = Self::check_pairing(inner, proof.w, point, &pvk.prepared_h, &pvk.prepared_beta_h);
+        end_timer!(check_time, || format!("Result: {}", result));
+        Ok(result)
+    }
+
+    /// Returns `true` if `e(inner, h) == e(w, beta_h - point * h)`.
+    ///
+    /// The equation is rearranged into `e(inner + point * w, h) * e(-w, beta_h) == 1`,
+    /// so that only the already-prepared `h` and `beta_h` are needed on the G2 side.
+    fn check_pairing(
+        mut inner: E::G1Projective,
+        w: E::G1Affine,
+        point: E::Fr,
+        prepared_h: &<E::G2Affine as PairingCurve>::Prepared,
+        prepared_beta_h: &<E::G2Affine as PairingCurve>::Prepared,
+    ) -> bool {
+        inner += &w.mul(point);
+        let affine_points = E::G1Projective::batch_normalization_into_affine(vec![-w.to_projective(), inner]);
+        let (neg_w, inner) = (affine_points[0], affine_points[1]);
+
+        let pairing_time = start_timer!(|| "Performing product of pairings");
+        let result = E::product_of_pairings(
+            [(&neg_w.prepare(), prepared_beta_h), (&inner.prepare(), prepared_h)].iter().copied(),
+        )
+        .is_one();
+        end_timer!(pairing_time);
+        result
     }
 
     /// Check that each `proof_i` in `proofs` is a valid proof of evaluation for
@@ -373,10 +449,54 @@ impl<E: PairingEngine> KZG10<E> {
         proofs: &[KZGProof<E>],
         rng: &mut R,
     ) -> Result<bool, PCError> {
+        Self::check_hiding_is_supported(vk.gamma_g.is_zero(), proofs)?;
+
         let check_time = start_timer!(|| format!("Checking {} evaluation proofs", commitments.len()));
-        let g = vk.g.to_projective();
-        let gamma_g = vk.gamma_g.to_projective();
+        let (mut total_c, total_w, g_multiplier, gamma_g_multiplier) =
+            Self::combine_batch(commitments, points, values, proofs, rng);
+        total_c -= &vk.g.mul(g_multiplier);
+        total_c -= &vk.gamma_g.mul(gamma_g_multiplier);
 
+        let result = Self::check_batch_pairing(total_c, total_w, &vk.prepared_h, &vk.prepared_beta_h);
+        end_timer!(check_time, || format!("Result: {}", result));
+        Ok(result)
+    }
+
+    /// Check that each `proof_i` in `proofs` is a valid proof of evaluation for
+    /// `commitment_i` at `point_i`, using a verifying key that has been prepared once
+    /// and is reused across many calls.
+    pub fn batch_check_prepared<R: RngCore>(
+        pvk: &PreparedVerifierKey<E>,
+        commitments: &[KZGCommitment<E>],
+        points: &[E::Fr],
+        values: &[E::Fr],
+        proofs: &[KZGProof<E>],
+        rng: &mut R,
+    ) -> Result<bool, PCError> {
+        let gamma_g_is_zero = pvk.prepared_gamma_g.first().map_or(true, |gamma_g| gamma_g.is_zero());
+        Self::check_hiding_is_supported(gamma_g_is_zero, proofs)?;
+
+        let check_time =
+            start_timer!(|| format!("Checking {} evaluation proofs with a prepared verifying key", commitments.len()));
+        let (mut total_c, total_w, g_multiplier, gamma_g_multiplier) =
+            Self::combine_batch(commitments, points, values, proofs, rng);
+        total_c -= &mul_with_doublings::<E>(&pvk.prepared_g, g_multiplier);
+        total_c -= &mul_with_doublings::<E>(&pvk.prepared_gamma_g, gamma_g_multiplier);
+
+        let result = Self::check_batch_pairing(total_c, total_w, &pvk.prepared_h, &pvk.prepared_beta_h);
+        end_timer!(check_time, || format!("Result: {}", result));
+        Ok(result)
+    }
+
+    /// Returns the random linear combinations `\sum_i r_i * (c_i + z_i * w_i)` and `\sum_i r_i * w_i`,
+    /// along with the coefficients `\sum_i r_i * v_i` of `g` and `\sum_i r_i * random_v_i` of `gamma_g`.
+    fn combine_batch<R: RngCore>(
+        commitments: &[KZGCommitment<E>],
+        points: &[E::Fr],
+        values: &[E::Fr],
+        proofs: &[KZGProof<E>],
+        rng: &mut R,
+    ) -> (E::G1Projective, E::G1Projective, E::Fr, E::Fr) {
         let mut total_c = <E::G1Projective>::zero();
         let mut total_w = <E::G1Projective>::zero();
 
@@ -401,10 +521,17 @@ impl<E: PairingEngine> KZG10<E> {
             // only from 128-bit strings.
             randomizer = u128::rand(rng).into();
         }
-        total_c -= &g.mul(g_multiplier);
-        total_c -= &gamma_g.mul(gamma_g_multiplier);
         end_timer!(combination_time);
+        (total_c, total_w, g_multiplier, gamma_g_multiplier)
+    }
 
+    /// Returns `true` if `e(-total_w, beta_h) * e(total_c, h) == 1`.
+    fn check_batch_pairing(
+        total_c: E::G1Projective,
+        total_w: E::G1Projective,
+        prepared_h: &<E::G2Affine as PairingCurve>::Prepared,
+        prepared_beta_h: &<E::G2Affine as PairingCurve>::Prepared,
+    ) -> bool {
         let to_affine_time = start_timer!(|| "Converting results to affine for pairing");
         let affine_points = E::G1Projective::batch_normalization_into_affine(vec![-total_w, total_c]);
         let (total_w, total_c) = (affine_points[0], affine_points[1]);
@@ -412,18 +539,30 @@ impl<E: PairingEngine> KZG10<E> {
 
         let pairing_time = start_timer!(|| "Performing product of pairings");
         let result = E::product_of_pairings(
-            [(&total_w.prepare(), &vk.prepared_beta_h), (&total_c.prepare(), &vk.prepared_h)].iter().copied(),
+            [(&total_w.prepare(), prepared_beta_h), (&total_c.prepare(), prepared_h)].iter().copied(),
         )
         .is_one();
         end_timer!(pairing_time);
-        end_timer!(check_time, || format!("Result: {}", result));
-        Ok(result)
+        result
     }
 
     pub(crate) fn check_degree_is_too_large(degree: usize, num_powers: usize) -> Result<(), PCError> {
-        let num_coefficients = degree + 1;
-        if num_coefficients > num_powers {
-            Err(PCError::TooManyCoefficients { num_coefficients, num_powers })
+        // Committing to a polynomial of degree `degree` requires `degree + 1` powers.
+        if degree >= num_powers {
+            Err(PCError::PolynomialDegreeTooLarge { degree, supported_degree: num_powers.saturating_sub(1) })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Ensures that no proof is hiding when the verifying key has no hiding generator,
+    /// as such proofs could never satisfy the pairing check.
+    pub fn check_hiding_is_supported<'a>(
+        gamma_g_is_zero: bool,
+        proofs: impl IntoIterator<Item = &'a KZGProof<E>>,
+    ) -> Result<(), PCError> {
+        if gamma_g_is_zero && proofs.into_iter().any(|proof| proof.is_hiding()) {
+            Err(PCError::HidingProofForNonHidingKey)
         } else {
             Ok(())
         }
@@ -482,6 +621,17 @@ fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<
     }
 }
 
+/// Returns `scalar * base`, given the successive doublings `[base, 2 * base, 4 * base, ...]` of `base`.
+fn mul_with_doublings<E: PairingEngine>(doublings: &[E::G1Affine], scalar: E::Fr) -> E::G1Projective {
+    let mut result = E::G1Projective::zero();
+    for (bit, base) in BitIteratorLE::new(scalar.to_bigint()).zip(doublings) {
+        if bit {
+            result.add_assign_mixed(base);
+        }
+    }
+    result
+}
+
 fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
     let to_bigint_time = start_timer!(|| "Converting polynomial coeffs to bigints");
     let coeffs = cfg_iter!(p).map(|s| s.to_bigint()).collect::<Vec<_>>();
@@ -494,9 +644,23 @@ mod tests {
     #![allow(non_camel_case_types)]
     #![allow(clippy::needless_borrow)]
     use super::*;
-    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
-    use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
-
+    use crate::fft::EvaluationDomain;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr, G1Affine};
+    use snarkvm_utilities::{
+        deserialize_affine_vec,
+        rand::TestRng,
+        read_affine_vec,
+        read_vec_exact,
+        CanonicalBytes,
+        CanonicalDeserialize,
+        CanonicalSerialize,
+        Compress,
+        FromBytes,
+        ToBytes,
+        Validate,
+    };
+
+    use rand::Rng;
     use std::borrow::Cow;
 
     type KZG_Bls12_377 = KZG10<Bls12_377>;
@@ -548,6 +712,282 @@ mod tests {
         assert_eq!(&pp_bytes, &pp_recovered_bytes);
     }
 
+    #[test]
+    fn test_kzg10_compressed_serialization() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let commitment = KZGCommitment::<Bls12_377>(rng.gen());
+            let proof = KZGProof::<Bls12_377> { w: rng.gen(), random_v: Some(rng.gen()) };
+
+            // Check the compressed round trip.
+            let compressed_commitment = commitment.to_bytes_le().unwrap();
+            assert_eq!(commitment, KZGCommitment::read_le(&compressed_commitment[..]).unwrap());
+            let compressed_proof = proof.to_bytes_le().unwrap();
+            assert_eq!(proof, KZGProof::read_le(&compressed_proof[..]).unwrap());
+
+            // Check the uncompressed round trip.
+            let mut uncompressed_commitment = vec![];
+            commitment.write_le_with(&mut uncompressed_commitment, Compress::No).unwrap();
+            let candidate = KZGCommitment::read_le_with(&uncompressed_commitment[..], Compress::No, Validate::Yes);
+            assert_eq!(commitment, candidate.unwrap());
+            let mut uncompressed_proof = vec![];
+            proof.write_le_with(&mut uncompressed_proof, Compress::No).unwrap();
+            let candidate = KZGProof::read_le_with(&uncompressed_proof[..], Compress::No, Validate::Yes);
+            assert_eq!(proof, candidate.unwrap());
+
+            // Check that the compressed encoding halves the size of the point.
+            assert_eq!(compressed_commitment.len(), 48);
+            assert_eq!(uncompressed_commitment.len(), 96);
+            assert_eq!(uncompressed_proof.len() - compressed_proof.len(), 48);
+        }
+    }
+
+    /// Checks that `value` round trips in both modes, that `ToBytes` uses the declared mode,
+    /// and that the bytes of one mode are not read back in full as `value` in the other mode.
+    fn check_serialization_modes<T: CanonicalBytes + ToBytes + PartialEq + core::fmt::Debug>(value: &T) {
+        for (compress, other) in [(Compress::Yes, Compress::No), (Compress::No, Compress::Yes)] {
+            let mut bytes = vec![];
+            value.write_le_with(&mut bytes, compress).unwrap();
+            assert_eq!(bytes.len(), value.serialized_size(compress));
+            if compress == T::COMPRESS {
+                assert_eq!(bytes, value.to_bytes_le().unwrap());
+            }
+
+            for validate in [Validate::Yes, Validate::No] {
+                assert_eq!(*value, T::read_le_with(&bytes[..], compress, validate).unwrap());
+                // A prefix of the bytes may decode in the other mode, but it must not consume them as `value`.
+                let mut reader = &bytes[..];
+                if let Ok(candidate) = T::read_le_with(&mut reader, other, validate) {
+                    assert!(candidate != *value || !reader.is_empty());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kzg10_serialization_modes() {
+        let rng = &mut TestRng::default();
+
+        let pp = KZG_Bls12_377::load_srs(10).unwrap();
+        let (_, vk) = KZG_Bls12_377::trim(&pp, 10, Some(1));
+        check_serialization_modes(&vk);
+
+        for _ in 0..100 {
+            check_serialization_modes(&KZGCommitment::<Bls12_377>(rng.gen()));
+            check_serialization_modes(&KZGProof::<Bls12_377> { w: rng.gen(), random_v: None });
+            check_serialization_modes(&KZGProof::<Bls12_377> { w: rng.gen(), random_v: Some(rng.gen()) });
+        }
+
+        // The defaults must preserve the existing compressed encodings.
+        assert_eq!(KZGCommitment::<Bls12_377>::COMPRESS, Compress::Yes);
+        assert_eq!(KZGProof::<Bls12_377>::COMPRESS, Compress::Yes);
+        assert_eq!(VerifierKey::<Bls12_377>::COMPRESS, Compress::Yes);
+
+        // Sample a point on the curve that is not in the prime-order subgroup.
+        let invalid_point = loop {
+            if let Some(point) = G1Affine::from_x_coordinate(Fq::rand(rng), true) {
+                if !point.is_in_correct_subgroup_assuming_on_curve() {
+                    break point;
+                }
+            }
+        };
+
+        // Ensure the point is rejected with validation, and accepted without, in either mode.
+        let commitment = KZGCommitment::<Bls12_377>(invalid_point);
+        let proof = KZGProof::<Bls12_377> { w: invalid_point, random_v: Some(rng.gen()) };
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = vec![];
+            commitment.write_le_with(&mut bytes, compress).unwrap();
+            assert!(KZGCommitment::<Bls12_377>::read_le_with(&bytes[..], compress, Validate::Yes).is_err());
+            assert_eq!(commitment, KZGCommitment::read_le_with(&bytes[..], compress, Validate::No).unwrap());
+
+            let mut bytes = vec![];
+            proof.write_le_with(&mut bytes, compress).unwrap();
+            assert!(KZGProof::<Bls12_377>::read_le_with(&bytes[..], compress, Validate::Yes).is_err());
+            assert_eq!(proof, KZGProof::read_le_with(&bytes[..], compress, Validate::No).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_kzg10_batch_validate() {
+        let rng = &mut TestRng::default();
+
+        assert!(KZGCommitment::<Bls12_377>::batch_validate(&[]).is_ok());
+
+        // Sample a point on the curve that is not in the prime-order subgroup.
+        let invalid_point = loop {
+            if let Some(point) = G1Affine::from_x_coordinate(Fq::rand(rng), true) {
+                if !point.is_in_correct_subgroup_assuming_on_curve() {
+                    break point;
+                }
+            }
+        };
+
+        // Check batches that are checked one by one, and batches that are checked with subset sums.
+        for num_commitments in [100, 1000] {
+            let mut commitments =
+                (0..num_commitments).map(|_| KZGCommitment::<Bls12_377>(rng.gen())).collect::<Vec<_>>();
+            commitments[num_commitments / 2] = KZGCommitment(G1Affine::zero());
+            assert!(KZGCommitment::batch_validate(&commitments).is_ok());
+
+            // Ensure a single invalid commitment is caught, wherever it is in the batch.
+            for index in [0, 42, num_commitments - 1] {
+                let original = commitments[index];
+                commitments[index] = KZGCommitment(invalid_point);
+                let error = KZGCommitment::batch_validate(&commitments).unwrap_err();
+                assert!(error.to_string().contains(&format!("Commitment {index} ")));
+                commitments[index] = original;
+            }
+
+            // Ensure two invalid commitments are caught, even when their sum is in the subgroup.
+            commitments[42] = KZGCommitment(invalid_point);
+            commitments[84] = KZGCommitment(-invalid_point);
+            let error = KZGCommitment::batch_validate(&commitments).unwrap_err();
+            assert!(error.to_string().contains("Commitment 42 "));
+        }
+
+        // Ensure the unchecked read accepts the point, while the checked read rejects it.
+        let bytes = KZGCommitment::<Bls12_377>(invalid_point).to_bytes_le().unwrap();
+        assert!(KZGCommitment::<Bls12_377>::read_le_unchecked(&bytes[..]).is_ok());
+        assert!(KZGCommitment::<Bls12_377>::read_le(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_read_affine_vec_matches_element_wise_reads() {
+        let rng = &mut TestRng::default();
+
+        let mut points = (0..1000).map(|_| rng.gen()).collect::<Vec<G1Affine>>();
+        points[500] = G1Affine::zero();
+
+        // Check the `ToBytes` encoding.
+        let bytes = points.iter().flat_map(|point| point.to_bytes_le().unwrap()).collect::<Vec<_>>();
+        let expected = read_vec_exact::<G1Affine, _>(&bytes[..], points.len()).unwrap();
+        assert_eq!(expected, points);
+        assert_eq!(expected, read_affine_vec::<G1Affine, _>(&bytes[..], points.len(), Validate::Yes).unwrap());
+
+        // Check the canonical encodings, without the length prefix.
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = vec![];
+            points.serialize_with_mode(&mut bytes, compress).unwrap();
+            let expected = Vec::<G1Affine>::deserialize_with_mode(&bytes[..], compress, Validate::Yes).unwrap();
+            assert_eq!(expected, points);
+            for validate in [Validate::Yes, Validate::No] {
+                let candidate =
+                    deserialize_affine_vec::<G1Affine, _>(&bytes[8..], points.len(), compress, validate).unwrap();
+                assert_eq!(expected, candidate);
+            }
+        }
+
+        // Sample a point on the curve that is not in the prime-order subgroup.
+        let invalid_point = loop {
+            if let Some(point) = G1Affine::from_x_coordinate(Fq::rand(rng), true) {
+                if !point.is_in_correct_subgroup_assuming_on_curve() {
+                    break point;
+                }
+            }
+        };
+        points[42] = invalid_point;
+
+        // Ensure the bulk reads reject the point with validation, and accept it without.
+        let bytes = points.iter().flat_map(|point| point.to_bytes_le().unwrap()).collect::<Vec<_>>();
+        assert!(read_affine_vec::<G1Affine, _>(&bytes[..], points.len(), Validate::Yes).is_err());
+        assert_eq!(points, read_affine_vec::<G1Affine, _>(&bytes[..], points.len(), Validate::No).unwrap());
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = vec![];
+            points.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert!(deserialize_affine_vec::<G1Affine, _>(&bytes[8..], points.len(), compress, Validate::Yes).is_err());
+            let candidate = deserialize_affine_vec::<G1Affine, _>(&bytes[8..], points.len(), compress, Validate::No);
+            assert_eq!(points, candidate.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_read_affine_vec_rejects_malformed_points() {
+        let rng = &mut TestRng::default();
+
+        let mut points = (0..100).map(|_| rng.gen()).collect::<Vec<G1Affine>>();
+        let index = rng.gen_range(0..points.len());
+
+        // Move a point off the curve.
+        points[index].y += Fq::one();
+        assert!(!points[index].is_on_curve());
+
+        // Ensure the bulk reads reject the point with validation, and accept it without, like the element-wise reads.
+        let bytes = points.iter().flat_map(|point| point.to_bytes_le().unwrap()).collect::<Vec<_>>();
+        assert!(read_affine_vec::<G1Affine, _>(&bytes[..], points.len(), Validate::Yes).is_err());
+        assert_eq!(points, read_affine_vec::<G1Affine, _>(&bytes[..], points.len(), Validate::No).unwrap());
+        assert_eq!(points, read_vec_exact::<G1Affine, _>(&bytes[..], points.len()).unwrap());
+
+        let mut uncompressed = vec![];
+        points.serialize_uncompressed(&mut uncompressed).unwrap();
+        assert!(Vec::<G1Affine>::deserialize_uncompressed(&uncompressed[..]).is_err());
+        let length = points.len();
+        let read_uncompressed =
+            |bytes: &[u8], validate| deserialize_affine_vec::<G1Affine, _>(&bytes[8..], length, Compress::No, validate);
+        assert!(read_uncompressed(&uncompressed, Validate::Yes).is_err());
+        assert_eq!(points, read_uncompressed(&uncompressed, Validate::No).unwrap());
+
+        // Ensure an invalid infinity flag is rejected, even without validation.
+        let mut malformed = bytes;
+        let element_size = malformed.len() / points.len();
+        malformed[(index + 1) * element_size - 1] = 2;
+        assert!(read_vec_exact::<G1Affine, _>(&malformed[..], points.len()).is_err());
+        assert!(read_affine_vec::<G1Affine, _>(&malformed[..], points.len(), Validate::No).is_err());
+
+        // Ensure a compressed x-coordinate with no point on the curve is rejected, even without validation.
+        points[index] = rng.gen();
+        let mut compressed = vec![];
+        points.serialize_compressed(&mut compressed).unwrap();
+        let x = loop {
+            let x = Fq::rand(rng);
+            if G1Affine::from_x_coordinate(x, true).is_none() {
+                break x;
+            }
+        };
+        let element_size = (compressed.len() - 8) / points.len();
+        let start = 8 + index * element_size;
+        x.serialize_compressed(&mut compressed[start..start + element_size]).unwrap();
+        assert!(Vec::<G1Affine>::deserialize_compressed_unchecked(&compressed[..]).is_err());
+        let result = deserialize_affine_vec::<G1Affine, _>(&compressed[8..], points.len(), Compress::Yes, Validate::No);
+        assert!(result.is_err());
+
+        // Ensure a truncated input is rejected.
+        assert!(read_uncompressed(&uncompressed[..uncompressed.len() - 1], Validate::No).is_err());
+    }
+
+    #[test]
+    fn test_kzg10_malformed_compressed_commitment() {
+        let rng = &mut TestRng::default();
+
+        let mut num_off_curve = 0;
+        let mut num_off_subgroup = 0;
+        while num_off_curve < 10 || num_off_subgroup < 10 {
+            let x = Fq::rand(rng);
+            match G1Affine::from_x_coordinate(x, true) {
+                // An x-coordinate that is not on the curve must be rejected.
+                None => {
+                    let bytes = x.to_bytes_le().unwrap();
+                    assert_eq!(bytes.len(), 48);
+                    assert!(KZGCommitment::<Bls12_377>::read_le(&bytes[..]).is_err());
+                    num_off_curve += 1;
+                }
+                // A point on the curve, but outside of the prime-order subgroup, must be rejected.
+                Some(point) if !point.is_in_correct_subgroup_assuming_on_curve() => {
+                    let mut bytes = vec![];
+                    point.serialize_compressed(&mut bytes).unwrap();
+                    assert!(KZGCommitment::<Bls12_377>::read_le(&bytes[..]).is_err());
+
+                    let mut bytes = vec![];
+                    point.serialize_uncompressed(&mut bytes).unwrap();
+                    assert!(KZGCommitment::<Bls12_377>::read_le_with(&bytes[..], Compress::No, Validate::Yes).is_err());
+                    num_off_subgroup += 1;
+                }
+                Some(_) => continue,
+            }
+        }
+    }
+
     fn end_to_end_test_template<E: PairingEngine>() -> Result<(), PCError> {
         let rng = &mut TestRng::default();
         for _ in 0..100 {
@@ -558,6 +998,7 @@ mod tests {
             let pp = KZG10::<E>::load_srs(degree)?;
             let hiding_bound = Some(1);
             let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
+            let pvk = PreparedVerifierKey::from(&vk);
             let p = DensePolynomial::rand(degree, rng);
             let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, &AtomicBool::new(false), Some(rng))?;
             let point = E::Fr::rand(rng);
@@ -570,6 +1011,84 @@ mod tests {
                 p.degree(),
                 hiding_bound,
             );
+            assert!(KZG10::<E>::check_prepared(&pvk, &comm, point, value, &proof)?);
+            assert!(!KZG10::<E>::check_prepared(&pvk, &comm, point, value + E::Fr::one(), &proof)?);
+        }
+        Ok(())
+    }
+
+    fn hiding_consistency_test_template<E: PairingEngine>() -> Result<(), PCError> {
+        let rng = &mut TestRng::default();
+        let degree = 20;
+        let pp = KZG10::<E>::load_srs(degree)?;
+        let (ck, vk) = KZG10::trim(&pp, degree, Some(1));
+        // A verifying key without a hiding generator, as used by the coinbase puzzle.
+        let non_hiding_vk = VerifierKey { gamma_g: E::G1Affine::zero(), ..vk.clone() };
+        let non_hiding_pvk = PreparedVerifierKey::from(&non_hiding_vk);
+
+        let p = DensePolynomial::rand(degree, rng);
+        let point = E::Fr::rand(rng);
+        let value = p.evaluate(point);
+
+        // A hiding proof must be rejected by a non-hiding verifying key.
+        let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), Some(1), &AtomicBool::new(false), Some(rng))?;
+        let proof = KZG10::<E>::open(&ck, &p, point, &rand)?;
+        assert!(proof.is_hiding());
+        assert!(KZG10::<E>::check(&vk, &comm, point, value, &proof)?);
+        assert!(matches!(
+            KZG10::<E>::check(&non_hiding_vk, &comm, point, value, &proof),
+            Err(PCError::HidingProofForNonHidingKey)
+        ));
+        assert!(matches!(
+            KZG10::<E>::check_prepared(&non_hiding_pvk, &comm, point, value, &proof),
+            Err(PCError::HidingProofForNonHidingKey)
+        ));
+        assert!(matches!(
+            KZG10::<E>::batch_check(&non_hiding_vk, &[comm], &[point], &[value], &[proof], rng),
+            Err(PCError::HidingProofForNonHidingKey)
+        ));
+        assert!(matches!(
+            KZG10::<E>::batch_check_prepared(&non_hiding_pvk, &[comm], &[point], &[value], &[proof], rng),
+            Err(PCError::HidingProofForNonHidingKey)
+        ));
+
+        // A non-hiding proof is accepted by both verifying keys.
+        let (comm, rand) = KZG10::<E>::commit(&ck, &(&p).into(), None, &AtomicBool::new(false), None)?;
+        let proof = KZG10::<E>::open(&ck, &p, point, &rand)?;
+        assert!(!proof.is_hiding());
+        assert!(KZG10::<E>::check(&vk, &comm, point, value, &proof)?);
+        assert!(KZG10::<E>::check(&non_hiding_vk, &comm, point, value, &proof)?);
+        assert!(KZG10::<E>::check_prepared(&non_hiding_pvk, &comm, point, value, &proof)?);
+        Ok(())
+    }
+
+    fn witness_polynomial_test_template<E: PairingEngine>() -> Result<(), PCError> {
+        let rng = &mut TestRng::default();
+        let degree = 50;
+        let pp = KZG10::<E>::load_srs(degree)?;
+        let (ck, _) = KZG10::trim(&pp, degree, Some(1));
+        for hiding_bound in [None, Some(1)] {
+            let p = DensePolynomial::rand(degree, rng);
+            let (_, rand) = KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, &AtomicBool::new(false), Some(rng))?;
+            let point = E::Fr::rand(rng);
+
+            // Compute the witness polynomials by long division.
+            let divisor = DensePolynomial::from_coefficients_vec(vec![-point, E::Fr::one()]);
+            let witness_polynomial = &p / &divisor;
+            let hiding_witness_polynomial = rand.is_hiding().then(|| &rand.blinding_polynomial / &divisor);
+            let (expected_witness, expected_hiding_witness) = KZG10::compute_witness_polynomial(&p, point, &rand)?;
+            assert_eq!(witness_polynomial, expected_witness);
+            assert_eq!(hiding_witness_polynomial, expected_hiding_witness);
+
+            // Ensure the proof is unchanged.
+            let expected = KZG10::<E>::open_with_witness_polynomial(
+                &ck,
+                point,
+                &rand,
+                &witness_polynomial,
+                hiding_witness_polynomial.as_ref(),
+            )?;
+            assert_eq!(KZG10::<E>::open(&ck, &p, point, &rand)?, expected);
         }
         Ok(())
     }
@@ -628,6 +1147,133 @@ mod tests {
                 proofs.push(proof);
             }
             assert!(KZG10::<E>::batch_check(&vk, &comms, &points, &values, &proofs, rng)?);
+
+            // Ensure the prepared variant agrees with `batch_check`, including on an invalid batch.
+            let pvk = PreparedVerifierKey::from(&vk);
+            assert!(KZG10::<E>::batch_check_prepared(&pvk, &comms, &points, &values, &proofs, rng)?);
+            values[0] += E::Fr::one();
+            assert!(!KZG10::<E>::batch_check(&vk, &comms, &points, &values, &proofs, rng)?);
+            assert!(!KZG10::<E>::batch_check_prepared(&pvk, &comms, &points, &values, &proofs, rng)?);
+        }
+        Ok(())
+    }
+
+    fn commit_evaluations_test_template<E: PairingEngine>() -> Result<(), PCError> {
+        let rng = &mut TestRng::default();
+        for log_size in 1..8 {
+            let size = 1 << log_size;
+            let domain = EvaluationDomain::<E::Fr>::new(size).unwrap();
+            let pp = KZG10::<E>::load_srs(size)?;
+            let hiding_bound = Some(1);
+            let (ck, _) = KZG10::trim(&pp, size - 1, hiding_bound);
+            let lagrange_basis = LagrangeBasis {
+                lagrange_basis_at_beta_g: Cow::Owned(pp.lagrange_basis(domain)?),
+                powers_of_beta_times_gamma_g: ck.powers_of_beta_times_gamma_g.clone(),
+                domain,
+            };
+
+            let p = DensePolynomial::rand(size - 1, rng);
+            let evaluations = p.evaluate_over_domain_by_ref(domain);
+
+            // Check the non-hiding commitments are equal.
+            let (expected, _) = KZG10::<E>::commit(&ck, &(&p).into(), None, &AtomicBool::new(false), None)?;
+            let (candidate, _) =
+                KZG10::<E>::commit_evaluations(&lagrange_basis, &evaluations, None, &AtomicBool::new(false), None)?;
+            assert_eq!(expected, candidate);
+
+            // Check the hiding commitments are equal, when sampled with the same randomness.
+            let seed = rng.next_u64();
+            let (expected, expected_rand) = KZG10::<E>::commit(
+                &ck,
+                &(&p).into(),
+                hiding_bound,
+                &AtomicBool::new(false),
+                Some(&mut TestRng::fixed(seed)),
+            )?;
+            let (candidate, candidate_rand) = KZG10::<E>::commit_evaluations(
+                &lagrange_basis,
+                &evaluations,
+                hiding_bound,
+                &AtomicBool::new(false),
+                Some(&mut TestRng::fixed(seed)),
+            )?;
+            assert_eq!(expected, candidate);
+            assert_eq!(expected_rand, candidate_rand);
+
+            // Check that evaluations over a mismatched domain are rejected.
+            let other_domain = EvaluationDomain::<E::Fr>::new(2 * size).unwrap();
+            let other_evaluations = p.evaluate_over_domain_by_ref(other_domain);
+            let terminator = AtomicBool::new(false);
+            let result = KZG10::<E>::commit_evaluations(&lagrange_basis, &other_evaluations, None, &terminator, None);
+            assert!(result.is_err());
+        }
+        Ok(())
+    }
+
+    /// Checks the given `(commitment, point, value, proof)` instances with `KZG10::batch_check_prepared`.
+    fn batch_check_instances<E: PairingEngine>(
+        pvk: &PreparedVerifierKey<E>,
+        instances: &[(KZGCommitment<E>, E::Fr, E::Fr, KZGProof<E>)],
+        rng: &mut TestRng,
+    ) -> Result<bool, PCError> {
+        let (commitments, points, values, proofs): (Vec<_>, Vec<_>, Vec<_>, Vec<_>) =
+            instances.iter().copied().multiunzip();
+        KZG10::<E>::batch_check_prepared(pvk, &commitments, &points, &values, &proofs, rng)
+    }
+
+    fn batch_check_multi_point_test_template<E: PairingEngine>() -> Result<(), PCError> {
+        let rng = &mut TestRng::default();
+        for hiding_bound in [None, Some(1)] {
+            let degree = 16;
+            let pp = KZG10::<E>::load_srs(degree)?;
+            let (ck, vk) = KZG10::trim(&pp, degree, hiding_bound);
+            let pvk = PreparedVerifierKey::from(&vk);
+
+            let shared_point = E::Fr::rand(rng);
+            let mut equal_point_instances = Vec::new();
+            let mut distinct_point_instances = Vec::new();
+
+            for _ in 0..10 {
+                let p = DensePolynomial::rand(degree, rng);
+                let (comm, rand) =
+                    KZG10::<E>::commit(&ck, &(&p).into(), hiding_bound, &AtomicBool::new(false), Some(rng))?;
+
+                // Open the polynomial at the shared point.
+                let value = p.evaluate(shared_point);
+                let proof = KZG10::<E>::open(&ck, &p, shared_point, &rand)?;
+                equal_point_instances.push((comm, shared_point, value, proof));
+
+                // Open the polynomial at a fresh point.
+                let point = E::Fr::rand(rng);
+                let value = p.evaluate(point);
+                let proof = KZG10::<E>::open(&ck, &p, point, &rand)?;
+                distinct_point_instances.push((comm, point, value, proof));
+            }
+
+            assert!(batch_check_instances(&pvk, &[], rng)?);
+            assert!(batch_check_instances(&pvk, &equal_point_instances, rng)?);
+            assert!(batch_check_instances(&pvk, &distinct_point_instances, rng)?);
+
+            let mut mixed_instances = equal_point_instances.clone();
+            mixed_instances.extend_from_slice(&distinct_point_instances);
+            assert!(batch_check_instances(&pvk, &mixed_instances, rng)?);
+
+            // Corrupt a single instance, and ensure the batch is rejected.
+            for instances in [equal_point_instances, distinct_point_instances, mixed_instances] {
+                let index = rng.gen_range(0..instances.len());
+
+                let mut corrupted = instances.clone();
+                corrupted[index].2 += E::Fr::one();
+                assert!(!batch_check_instances(&pvk, &corrupted, rng)?);
+
+                let mut corrupted = instances.clone();
+                corrupted[index].1 += E::Fr::one();
+                assert!(!batch_check_instances(&pvk, &corrupted, rng)?);
+
+                let mut corrupted = instances;
+                corrupted[index].3.w = corrupted[index].3.w.to_projective().double().into();
+                assert!(!batch_check_instances(&pvk, &corrupted, rng)?);
+            }
         }
         Ok(())
     }
@@ -637,6 +1283,16 @@ mod tests {
         end_to_end_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_hiding_consistency() {
+        hiding_consistency_test_template::<Bls12_377>().expect("test failed for bls12-377");
+    }
+
+    #[test]
+    fn test_witness_polynomial() {
+        witness_polynomial_test_template::<Bls12_377>().expect("test failed for bls12-377");
+    }
+
     #[test]
     fn test_linear_polynomial() {
         linear_polynomial_test_template::<Bls12_377>().expect("test failed for bls12-377");
@@ -647,6 +1303,16 @@ mod tests {
         batch_check_test_template::<Bls12_377>().expect("test failed for bls12-377");
     }
 
+    #[test]
+    fn test_batch_check_multi_point() {
+        batch_check_multi_point_test_template::<Bls12_377>().expect("test failed for bls12-377");
+    }
+
+    #[test]
+    fn test_commit_evaluations() {
+        commit_evaluations_test_template::<Bls12_377>().expect("test failed for bls12-377");
+    }
+
     #[test]
     fn test_degree_is_too_large() {
         let rng = &mut TestRng::default();
@@ -659,4 +1325,65 @@ mod tests {
         assert!(p.degree() > max_degree);
         assert!(KZG_Bls12_377::check_degree_is_too_large(p.degree(), powers.size()).is_err());
     }
+
+    #[test]
+    fn test_degree_boundary() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = 123;
+        let pp = KZG_Bls12_377::load_srs(max_degree).unwrap();
+        let (powers, _) = KZG_Bls12_377::trim(&pp, max_degree, None);
+        assert_eq!(powers.supported_degree(), max_degree);
+        assert_eq!(powers.size(), max_degree + 1);
+
+        let point = Fr::rand(rng);
+        let rand = KZGRandomness::empty();
+
+        // A polynomial of the supported degree can be committed to and opened.
+        let p = DensePolynomial::<Fr>::rand(max_degree, rng);
+        assert!(KZG_Bls12_377::commit(&powers, &(&p).into(), None, &AtomicBool::new(false), None).is_ok());
+        assert!(KZG_Bls12_377::open(&powers, &p, point, &rand).is_ok());
+
+        // A polynomial of one degree more is rejected up front.
+        let p = DensePolynomial::<Fr>::rand(max_degree + 1, rng);
+        let is_degree_too_large = |result: Result<_, PCError>| {
+            matches!(result, Err(PCError::PolynomialDegreeTooLarge { degree, supported_degree })
+                if degree == max_degree + 1 && supported_degree == max_degree)
+        };
+        assert!(is_degree_too_large(
+            KZG_Bls12_377::commit(&powers, &(&p).into(), None, &AtomicBool::new(false), None).map(|_| ())
+        ));
+        assert!(is_degree_too_large(KZG_Bls12_377::open(&powers, &p, point, &rand).map(|_| ())));
+
+        // A window of the powers supports correspondingly fewer degrees.
+        let window = powers.window(0, max_degree).unwrap();
+        assert_eq!(window.supported_degree(), max_degree - 1);
+        assert_eq!(&window.powers_of_beta_g[..], &powers.powers_of_beta_g[..max_degree]);
+        let p = DensePolynomial::<Fr>::rand(max_degree, rng);
+        assert!(KZG_Bls12_377::commit(&window, &(&p).into(), None, &AtomicBool::new(false), None).is_err());
+        let p = DensePolynomial::<Fr>::rand(max_degree - 1, rng);
+        assert!(KZG_Bls12_377::commit(&window, &(&p).into(), None, &AtomicBool::new(false), None).is_ok());
+
+        // A window must lie within the powers.
+        assert!(powers.window(0, max_degree + 1).is_some());
+        assert!(powers.window(0, max_degree + 2).is_none());
+        assert!(powers.window(2, 1).is_none());
+    }
+
+    #[test]
+    fn test_degree_bounds_union() {
+        let max_degree = 64;
+        let marlin = KZGDegreeBounds::Marlin;
+        let list = KZGDegreeBounds::List(vec![5, 14, 3]);
+
+        assert_eq!(marlin.union::<Fr>(&KZGDegreeBounds::None, max_degree), KZGDegreeBounds::Marlin);
+        assert_eq!(KZGDegreeBounds::None.union::<Fr>(&list, max_degree), list);
+        assert_eq!(marlin.union::<Fr>(&KZGDegreeBounds::All, max_degree), KZGDegreeBounds::All);
+        assert_eq!(marlin.union::<Fr>(&marlin, max_degree), KZGDegreeBounds::Marlin);
+
+        // Mixing a preset with an explicit list yields the sorted, deduplicated union.
+        let union = marlin.union::<Fr>(&list, max_degree);
+        assert_eq!(union, KZGDegreeBounds::List(vec![0, 2, 3, 5, 6, 14, 30, 62]));
+        assert_eq!(union, list.union::<Fr>(&marlin, max_degree));
+    }
 }
@@ -16,9 +16,17 @@
 
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10, Prepare};
 use hashbrown::HashMap;
-use snarkvm_curves::{PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
-use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
+use snarkvm_utilities::{
+    check_length_bounded,
+    error,
+    read_affine_vec,
+    read_vec_bounded,
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
 
 use std::{
     borrow::{Borrow, Cow},
@@ -86,50 +94,37 @@ pub struct CommitterKey<E: PairingEngine> {
     pub max_degree: usize,
 }
 
+/// The maximum number of group elements that are read for each vector in a `CommitterKey`.
+const MAX_NUM_SERIALIZED_POWERS: usize = 1 << 28;
+
+/// Reads a vector of at most `MAX_NUM_SERIALIZED_POWERS` group elements, prefixed by its length as a `u32`.
+fn read_powers<G: AffineCurve, R: Read>(mut reader: R) -> io::Result<Vec<G>> {
+    let length = check_length_bounded(u32::read_le(&mut reader)?, MAX_NUM_SERIALIZED_POWERS)?;
+    read_affine_vec(reader, length, Validate::No)
+}
+
 impl<E: PairingEngine> FromBytes for CommitterKey<E> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
         // Deserialize `powers`.
-        let powers_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut powers_of_beta_g = Vec::with_capacity(powers_len as usize);
-        for _ in 0..powers_len {
-            let power: E::G1Affine = FromBytes::read_le(&mut reader)?;
-            powers_of_beta_g.push(power);
-        }
+        let powers_of_beta_g: Vec<E::G1Affine> = read_powers(&mut reader)?;
 
         // Deserialize `lagrange_basis_at_beta`.
         let lagrange_bases_at_beta_len: u32 = FromBytes::read_le(&mut reader)?;
+        let lagrange_bases_at_beta_len = check_length_bounded(lagrange_bases_at_beta_len, MAX_NUM_SERIALIZED_POWERS)?;
         let mut lagrange_bases_at_beta_g = BTreeMap::new();
         for _ in 0..lagrange_bases_at_beta_len {
             let size: u32 = FromBytes::read_le(&mut reader)?;
-            let mut basis = Vec::with_capacity(size as usize);
-            for _ in 0..size {
-                let power: E::G1Affine = FromBytes::read_le(&mut reader)?;
-                basis.push(power);
-            }
-            lagrange_bases_at_beta_g.insert(size as usize, basis);
+            let size = check_length_bounded(size, MAX_NUM_SERIALIZED_POWERS)?;
+            lagrange_bases_at_beta_g.insert(size, read_affine_vec::<E::G1Affine, _>(&mut reader, size, Validate::No)?);
         }
 
         // Deserialize `powers_of_beta_times_gamma_g`.
-        let powers_of_beta_times_gamma_g_len: u32 = FromBytes::read_le(&mut reader)?;
-        let mut powers_of_beta_times_gamma_g = Vec::with_capacity(powers_of_beta_times_gamma_g_len as usize);
-        for _ in 0..powers_of_beta_times_gamma_g_len {
-            let powers_of_g: E::G1Affine = FromBytes::read_le(&mut reader)?;
-            powers_of_beta_times_gamma_g.push(powers_of_g);
-        }
+        let powers_of_beta_times_gamma_g: Vec<E::G1Affine> = read_powers(&mut reader)?;
 
         // Deserialize `shifted_powers_of_beta_g`.
         let has_shifted_powers_of_beta_g: bool = FromBytes::read_le(&mut reader)?;
         let shifted_powers_of_beta_g = match has_shifted_powers_of_beta_g {
-            true => {
-                let shifted_powers_len: u32 = FromBytes::read_le(&mut reader)?;
-                let mut shifted_powers_of_beta_g = Vec::with_capacity(shifted_powers_len as usize);
-                for _ in 0..shifted_powers_len {
-                    let shifted_power: E::G1Affine = FromBytes::read_le(&mut reader)?;
-                    shifted_powers_of_beta_g.push(shifted_power);
-                }
-
-                Some(shifted_powers_of_beta_g)
-            }
+            true => Some(read_powers::<E::G1Affine, _>(&mut reader)?),
             false => None,
         };
 
@@ -138,16 +133,10 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         let shifted_powers_of_beta_times_gamma_g = match has_shifted_powers_of_beta_times_gamma_g {
             true => {
                 let mut shifted_powers_of_beta_times_gamma_g = BTreeMap::new();
-                let shifted_powers_of_beta_times_gamma_g_num_elements: u32 = FromBytes::read_le(&mut reader)?;
-                for _ in 0..shifted_powers_of_beta_times_gamma_g_num_elements {
+                let num_elements: u32 = FromBytes::read_le(&mut reader)?;
+                for _ in 0..check_length_bounded(num_elements, MAX_NUM_SERIALIZED_POWERS)? {
                     let key: u32 = FromBytes::read_le(&mut reader)?;
-
-                    let value_len: u32 = FromBytes::read_le(&mut reader)?;
-                    let mut value = Vec::with_capacity(value_len as usize);
-                    for _ in 0..value_len {
-                        let val: E::G1Affine = FromBytes::read_le(&mut reader)?;
-                        value.push(val);
-                    }
+                    let value: Vec<E::G1Affine> = read_powers(&mut reader)?;
 
                     shifted_powers_of_beta_times_gamma_g.insert(key as usize, value);
                 }
@@ -161,14 +150,8 @@ impl<E: PairingEngine> FromBytes for CommitterKey<E> {
         let has_enforced_degree_bounds: bool = FromBytes::read_le(&mut reader)?;
         let enforced_degree_bounds = match has_enforced_degree_bounds {
             true => {
-                let enforced_degree_bounds_len: u32 = FromBytes::read_le(&mut reader)?;
-                let mut enforced_degree_bounds = Vec::with_capacity(enforced_degree_bounds_len as usize);
-                for _ in 0..enforced_degree_bounds_len {
-                    let enforced_degree_bound: u32 = FromBytes::read_le(&mut reader)?;
-                    enforced_degree_bounds.push(enforced_degree_bound as usize);
-                }
-
-                Some(enforced_degree_bounds)
+                let enforced_degree_bounds = read_vec_bounded::<u32, _>(&mut reader, MAX_NUM_SERIALIZED_POWERS)?;
+                Some(enforced_degree_bounds.into_iter().map(|degree_bound| degree_bound as usize).collect())
             }
             false => None,
         };
@@ -770,14 +753,19 @@ impl<E: PairingEngine> BatchLCProof<E> {
     }
 }
 
+impl<E: PairingEngine> CanonicalBytes for BatchLCProof<E> {
+    const COMPRESS: Compress = Compress::Yes;
+}
+
 impl<E: PairingEngine> FromBytes for BatchLCProof<E> {
     fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
-        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize struct"))
+        Self::read_le_with(&mut reader, Self::COMPRESS, Self::VALIDATE)
+            .map_err(|_| error("could not deserialize struct"))
     }
 }
 
 impl<E: PairingEngine> ToBytes for BatchLCProof<E> {
     fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
-        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
+        self.write_le_with(&mut writer, Self::COMPRESS).map_err(|_| error("could not serialize struct"))
     }
 }
@@ -84,6 +84,11 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             v
         });
 
+        // Ensure the parameters can verify every enforced degree bound.
+        if let Some(bound) = enforced_degree_bounds.iter().flatten().find(|bound| !pp.supports_degree_bound(**bound)) {
+            return Err(PCError::UnsupportedDegreeBound(*bound));
+        }
+
         let (shifted_powers_of_beta_g, shifted_powers_of_beta_times_gamma_g) = if let Some(enforced_degree_bounds) =
             enforced_degree_bounds.as_ref()
         {
@@ -144,7 +149,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             if size > pp.max_degree() + 1 {
                 return Err(PCError::LagrangeBasisSizeIsTooLarge);
             }
-            let domain = crate::fft::EvaluationDomain::new(size).unwrap();
+            let domain = crate::fft::EvaluationDomain::try_new(size)?;
             let lagrange_basis_at_beta_g = pp.lagrange_basis(domain)?;
             assert!(lagrange_basis_at_beta_g.len().is_power_of_two());
             lagrange_bases_at_beta_g.insert(domain.size(), lagrange_basis_at_beta_g);
@@ -271,7 +276,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
                         let rng_ref = rng.as_mut().map(|s| s as _);
                         match p {
                             PolynomialWithBasis::Lagrange { evaluations } => {
-                                let domain = crate::fft::EvaluationDomain::new(evaluations.evaluations.len()).unwrap();
+                                let domain = crate::fft::EvaluationDomain::try_new(evaluations.evaluations.len())?;
                                 let lagrange_basis = ck
                                     .lagrange_basis(domain)
                                     .ok_or(PCError::UnsupportedLagrangeBasisSize(domain.size()))?;
@@ -757,8 +762,12 @@ mod tests {
     #![allow(non_camel_case_types)]
 
     use super::{CommitterKey, SonicKZG10};
-    use crate::{crypto_hash::PoseidonSponge, polycommit::test_templates::*};
-    use snarkvm_curves::bls12_377::{Bls12_377, Fq};
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        fft::EvaluationDomain,
+        polycommit::{kzg10::KZGDegreeBounds, test_templates::*, PCError},
+    };
+    use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_utilities::{rand::TestRng, FromBytes, ToBytes};
 
     use rand::distributions::Distribution;
@@ -785,6 +794,35 @@ mod tests {
         assert_eq!(&ck_bytes, &ck_recovered_bytes);
     }
 
+    #[test]
+    fn test_trim_with_shared_degree_bounds() {
+        let max_degree = 64;
+        let supported_degree = 32;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+
+        // Trim a Marlin key, enforcing every preset bound the parameters support.
+        let marlin_bounds = KZGDegreeBounds::Marlin
+            .get_list::<Fr>(supported_degree)
+            .into_iter()
+            .filter(|bound| pp.supports_degree_bound(*bound))
+            .collect::<Vec<_>>();
+        let (_, vk) = PC_Bls12_377::trim(&pp, supported_degree, None, 1, Some(&marlin_bounds)).unwrap();
+        for bound in &marlin_bounds {
+            assert!(vk.get_shift_power(*bound).is_some());
+        }
+
+        // Trim a coinbase-style key, without degree bounds, from the same parameters.
+        let coinbase_size = (supported_degree + 1).next_power_of_two() >> 1;
+        let (ck, _) = PC_Bls12_377::trim(&pp, supported_degree, [coinbase_size], 0, None).unwrap();
+        assert!(ck.lagrange_basis(EvaluationDomain::new(coinbase_size).unwrap()).is_some());
+
+        // Trimming with a bound the parameters cannot verify must fail.
+        let bound = (0..supported_degree).find(|bound| !pp.supports_degree_bound(*bound));
+        let bound = bound.expect("The parameters should not support every degree bound below the supported degree");
+        let result = PC_Bls12_377::trim(&pp, supported_degree, None, 1, Some(&[bound]));
+        assert!(matches!(result, Err(PCError::UnsupportedDegreeBound(b)) if b == bound));
+    }
+
     #[test]
     fn test_single_poly() {
         single_poly_test::<Bls12_377, Sponge>().expect("test failed for bls12-377");
@@ -393,29 +393,30 @@ impl<F: PrimeField> UnnormalizedBivariateLagrangePoly<F> for EvaluationDomain<F>
         x: F,
         domain: &EvaluationDomain<F>,
     ) -> Vec<F> {
-        use snarkvm_utilities::{cfg_iter, cfg_iter_mut};
+        use snarkvm_utilities::cfg_iter_mut;
 
         #[cfg(feature = "parallel")]
         use rayon::prelude::*;
 
         let vanish_x = self.evaluate_vanishing_polynomial(x);
-        let elements = domain.elements().collect::<Vec<_>>();
+        #[cfg(feature = "parallel")]
+        let elements = domain.par_elements();
+        #[cfg(not(feature = "parallel"))]
+        let elements = domain.elements();
 
-        let mut denoms = cfg_iter!(elements).map(|e| x - e).collect::<Vec<_>>();
         if domain.size() <= self.size() {
+            let mut denoms = elements.map(|e| x - e).collect::<Vec<_>>();
             snarkvm_fields::batch_inversion_and_mul(&mut denoms, &vanish_x);
+            denoms
         } else {
+            // The numerators are computed in the same pass as the denominators, so that the
+            // elements are never materialized. `v_H(e)` vanishes whenever `e` is in `H`.
+            let (mut denoms, numerators): (Vec<_>, Vec<_>) =
+                elements.map(|e| (x - e, vanish_x - self.evaluate_vanishing_polynomial(e))).unzip();
             snarkvm_fields::batch_inversion(&mut denoms);
-            let ratio = domain.size() / self.size();
-            let mut numerators = vec![vanish_x; domain.size()];
-            cfg_iter_mut!(numerators).zip_eq(elements).enumerate().for_each(|(i, (n, e))| {
-                if i % ratio != 0 {
-                    *n -= self.evaluate_vanishing_polynomial(e);
-                }
-            });
-            cfg_iter_mut!(denoms).zip_eq(numerators).for_each(|(d, e)| *d *= e);
+            cfg_iter_mut!(denoms).zip_eq(numerators).for_each(|(d, n)| *d *= n);
+            denoms
         }
-        denoms
     }
 
     fn batch_eval_unnormalized_bivariate_lagrange_poly_with_diff_inputs(&self, x: F) -> Vec<F> {
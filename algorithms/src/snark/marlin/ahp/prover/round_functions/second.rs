@@ -85,7 +85,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             .zip_eq(batch_combiners)
             .zip(&state.x_poly)
             .map(|((b, &coeff), x_poly)| {
-                let mut z = b.w_poly.polynomial().as_dense().unwrap().mul_by_vanishing_poly(state.input_domain);
+                let mut z = b.w_poly.polynomial().to_dense().mul_by_vanishing_poly(state.input_domain);
                 // Zip safety: `x_poly` is smaller than `z_poly`.
                 z.coeffs.iter_mut().zip(&x_poly.coeffs).for_each(|(z, x)| *z += x);
                 cfg_iter_mut!(z.coeffs).for_each(|z| *z *= &coeff);
@@ -180,8 +180,8 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             cfg_iter!(first_msg.batches)
                 .zip_eq(batch_combiners)
                 .map(|(entry, combiner)| {
-                    let z_a = entry.z_a_poly.polynomial().as_dense().unwrap();
-                    let mut z_b = entry.z_b_poly.polynomial().as_dense().unwrap().clone();
+                    let z_a = entry.z_a_poly.polynomial().to_dense();
+                    let mut z_b = entry.z_b_poly.polynomial().to_dense().into_owned();
                     assert!(z_a.degree() < constraint_domain.size());
                     if MM::ZK {
                         assert_eq!(z_b.degree(), constraint_domain.size());
@@ -199,7 +199,7 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                         cfg_iter_mut!(z_b.coeffs).for_each(|b| *b *= eta_c);
                         z_b.coeffs[0] += F::one();
                         let mut multiplier = PolyMultiplier::new();
-                        multiplier.add_polynomial_ref(z_a, "z_a");
+                        multiplier.add_polynomial_ref(&z_a, "z_a");
                         multiplier.add_polynomial_ref(&z_b, "eta_c_z_b_plus_one");
                         multiplier.add_precomputation(fft_precomputation, ifft_precomputation);
                         let result = multiplier.multiply().unwrap();
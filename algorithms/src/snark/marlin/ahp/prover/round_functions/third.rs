@@ -18,13 +18,7 @@ use core::convert::TryInto;
 use std::collections::BTreeMap;
 
 use crate::{
-    fft::{
-        domain::{FFTPrecomputation, IFFTPrecomputation},
-        polynomial::PolyMultiplier,
-        DensePolynomial,
-        EvaluationDomain,
-        Evaluations as EvaluationsOnDomain,
-    },
+    fft::{domain::IFFTPrecomputation, DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
     polycommit::sonic_pc::{LabeledPolynomial, PolynomialInfo, PolynomialLabel},
     snark::marlin::{
         ahp::{indexer::CircuitInfo, verifier, AHPError, AHPForR1CS},
@@ -34,7 +28,7 @@ use crate::{
     },
 };
 use snarkvm_fields::{batch_inversion_and_mul, PrimeField};
-use snarkvm_utilities::{cfg_iter, cfg_iter_mut, ExecutionPool};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, cfg_iter_mut, ExecutionPool};
 
 use rand_core::RngCore;
 
@@ -96,7 +90,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 beta,
                 v_H_alpha_v_H_beta,
                 largest_non_zero_domain_size,
-                state.fft_precomputation(),
                 state.ifft_precomputation(),
             )
         });
@@ -110,7 +103,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 beta,
                 v_H_alpha_v_H_beta,
                 largest_non_zero_domain_size,
-                state.fft_precomputation(),
                 state.ifft_precomputation(),
             )
         });
@@ -124,13 +116,14 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
                 beta,
                 v_H_alpha_v_H_beta,
                 largest_non_zero_domain_size,
-                state.fft_precomputation(),
                 state.ifft_precomputation(),
             )
         });
 
-        let [(sum_a, lhs_a, g_a), (sum_b, lhs_b, g_b), (sum_c, lhs_c, g_c)]: [_; 3] =
-            pool.execute_all().try_into().unwrap();
+        let [result_a, result_b, result_c]: [_; 3] = pool.execute_all().try_into().unwrap();
+        let (sum_a, lhs_a, g_a) = result_a?;
+        let (sum_b, lhs_b, g_b) = result_b?;
+        let (sum_c, lhs_c, g_c) = result_c?;
 
         let msg = prover::ThirdMessage { sum_a, sum_b, sum_c };
         let oracles = prover::ThirdOracles { g_a, g_b, g_c };
@@ -152,16 +145,14 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
         beta: F,
         v_H_alpha_v_H_beta: F,
         largest_non_zero_domain_size: F,
-        fft_precomputation: &FFTPrecomputation<F>,
         ifft_precomputation: &IFFTPrecomputation<F>,
-    ) -> (F, DensePolynomial<F>, LabeledPolynomial<F>) {
+    ) -> Result<(F, DensePolynomial<F>, LabeledPolynomial<F>), AHPError> {
         let mut job_pool = snarkvm_utilities::ExecutionPool::with_capacity(2);
         job_pool.add_job(|| {
             let a_poly_time = start_timer!(|| "Computing a poly");
             let a_poly = {
-                let coeffs = cfg_iter!(arithmetization.val.as_dense().unwrap().coeffs())
-                    .map(|a| v_H_alpha_v_H_beta * a)
-                    .collect();
+                let val = arithmetization.val.to_dense();
+                let coeffs = cfg_iter!(val.coeffs()).map(|a| v_H_alpha_v_H_beta * a).collect();
                 DensePolynomial::from_coefficients_vec(coeffs)
             };
             end_timer!(a_poly_time);
@@ -204,25 +195,32 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
             .interpolate_with_pc(ifft_precomputation);
         end_timer!(f_poly_time);
         let g = DensePolynomial::from_coefficients_slice(&f.coeffs[1..]);
-        let h = &a_poly
-            - &{
-                let mut multiplier = PolyMultiplier::new();
-                multiplier.add_polynomial_ref(&b_poly, "b");
-                multiplier.add_polynomial_ref(&f, "f");
-                multiplier.add_precomputation(fft_precomputation, ifft_precomputation);
-                multiplier.multiply().unwrap()
-            };
         // Let K_max = largest_non_zero_domain;
         // Let K = non_zero_domain;
         // Let s := K_max.selector_polynomial(K) = (v_K_max / v_K) * (K.size() / K_max.size());
         // Let v_K_max := K_max.vanishing_polynomial();
         // Let v_K := K.vanishing_polynomial();
 
-        // Later on, we multiply `h` by s, and divide by v_K_max.
+        // Later on, we multiply `h = a - b * f` by s, and divide by v_K_max.
         // Substituting in s, we get that h * s / v_K_max = h / v_K * (K.size() / K_max.size());
         // That's what we're computing here.
-        let (mut h, remainder) = h.divide_by_vanishing_poly(non_zero_domain).unwrap();
-        assert!(remainder.is_zero());
+        // As `h` has degree less than `2 * K.size()`, we compute it over a coset of a domain of that size,
+        // where v_K does not vanish and can be divided out pointwise.
+        let h_time = start_timer!(|| "Computing h poly on coset");
+        let coset_domain =
+            EvaluationDomain::new(2 * non_zero_domain.size()).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        let offset = F::multiplicative_generator();
+        let [a_evals, b_evals, f_evals]: [_; 3] =
+            [&a_poly, &b_poly, &f].map(|p| coset_domain.coset_fft_with_offset(&p.coeffs, offset));
+        let h_evals = cfg_into_iter!(a_evals).zip_eq(b_evals).zip_eq(f_evals).map(|((a, b), f)| a - b * f).collect();
+        let h_evals =
+            EvaluationsOnDomain::from_vec_and_domain(h_evals, coset_domain).divide_on_coset(non_zero_domain, offset);
+        let mut h =
+            DensePolynomial::from_coefficients_vec(coset_domain.coset_ifft_with_offset(&h_evals.evaluations, offset));
+        end_timer!(h_time);
+        // `h` now interpolates `(a - b * f) / v_K` over the `2 * K.size()` points of the coset.
+        // It has degree less than `K.size()` exactly when `v_K` divides `a - b * f`, i.e. when the remainder is zero.
+        assert!(h.coeffs.iter().skip(non_zero_domain.size()).all(|c| c.is_zero()), "v_K does not divide h");
         let multiplier = non_zero_domain.size_as_field_element / largest_non_zero_domain_size;
         cfg_iter_mut!(h.coeffs).for_each(|c| *c *= multiplier);
 
@@ -230,6 +228,6 @@ impl<F: PrimeField, MM: MarlinMode> AHPForR1CS<F, MM> {
 
         assert!(h.degree() <= non_zero_domain.size() - 2);
         assert!(g.degree() <= non_zero_domain.size() - 2);
-        (f.coeffs[0], h, g)
+        Ok((f.coeffs[0], h, g))
     }
 }
@@ -38,14 +38,19 @@ impl<E: PairingEngine> Certificate<E> {
     }
 }
 
+impl<E: PairingEngine> CanonicalBytes for Certificate<E> {
+    const COMPRESS: Compress = Compress::Yes;
+}
+
 impl<E: PairingEngine> ToBytes for Certificate<E> {
     fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
-        Self::serialize_compressed(self, &mut w).map_err(|_| error("Failed to serialize certificate"))
+        self.write_le_with(&mut w, Self::COMPRESS).map_err(|_| error("Failed to serialize certificate"))
     }
 }
 
 impl<E: PairingEngine> FromBytes for Certificate<E> {
     fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
-        Self::deserialize_compressed(&mut r).map_err(|_| error("Failed to deserialize certificate"))
+        Self::read_le_with(&mut r, Self::COMPRESS, Self::VALIDATE)
+            .map_err(|_| error("Failed to deserialize certificate"))
     }
 }
@@ -30,8 +30,10 @@ use snarkvm_utilities::{
     string::String,
     FromBytes,
     FromBytesDeserializer,
+    FromHex,
     ToBytes,
     ToBytesSerializer,
+    ToHex,
     ToMinimalBits,
 };
 
@@ -153,15 +155,20 @@ impl<E: PairingEngine, MM: MarlinMode> ToMinimalBits for CircuitVerifyingKey<E,
     }
 }
 
+impl<E: PairingEngine, MM: MarlinMode> CanonicalBytes for CircuitVerifyingKey<E, MM> {
+    const COMPRESS: Compress = Compress::Yes;
+}
+
 impl<E: PairingEngine, MM: MarlinMode> FromBytes for CircuitVerifyingKey<E, MM> {
     fn read_le<R: Read>(r: R) -> io::Result<Self> {
-        Self::deserialize_compressed(r).map_err(|_| error("could not deserialize CircuitVerifyingKey"))
+        Self::read_le_with(r, Self::COMPRESS, Self::VALIDATE)
+            .map_err(|_| error("could not deserialize CircuitVerifyingKey"))
     }
 }
 
 impl<E: PairingEngine, MM: MarlinMode> ToBytes for CircuitVerifyingKey<E, MM> {
     fn write_le<W: Write>(&self, w: W) -> io::Result<()> {
-        self.serialize_compressed(w).map_err(|_| error("could not serialize CircuitVerifyingKey"))
+        self.write_le_with(w, Self::COMPRESS).map_err(|_| error("could not serialize CircuitVerifyingKey"))
     }
 }
 
@@ -203,15 +210,14 @@ impl<E: PairingEngine, MM: MarlinMode> FromStr for CircuitVerifyingKey<E, MM> {
 
     #[inline]
     fn from_str(vk_hex: &str) -> Result<Self, Self::Err> {
-        Self::from_bytes_le(&hex::decode(vk_hex)?)
+        Self::from_hex(vk_hex)
     }
 }
 
 impl<E: PairingEngine, MM: MarlinMode> fmt::Display for CircuitVerifyingKey<E, MM> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let vk_hex = hex::encode(self.to_bytes_le().expect("Failed to convert verifying key to bytes"));
-        write!(f, "{}", vk_hex)
+        write!(f, "{}", self.fmt_hex())
     }
 }
 
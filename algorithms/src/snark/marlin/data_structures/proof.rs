@@ -307,14 +307,18 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
     }
 }
 
+impl<E: PairingEngine> CanonicalBytes for Proof<E> {
+    const COMPRESS: Compress = Compress::Yes;
+}
+
 impl<E: PairingEngine> ToBytes for Proof<E> {
     fn write_le<W: Write>(&self, mut w: W) -> io::Result<()> {
-        Self::serialize_compressed(self, &mut w).map_err(|_| error("could not serialize Proof"))
+        self.write_le_with(&mut w, Self::COMPRESS).map_err(|_| error("could not serialize Proof"))
     }
 }
 
 impl<E: PairingEngine> FromBytes for Proof<E> {
     fn read_le<R: Read>(mut r: R) -> io::Result<Self> {
-        Self::deserialize_compressed(&mut r).map_err(|_| error("could not deserialize Proof"))
+        Self::read_le_with(&mut r, Self::COMPRESS, Self::VALIDATE).map_err(|_| error("could not deserialize Proof"))
     }
 }
@@ -0,0 +1,159 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::{
+    fft::{polynomial::MulScratch, DensePolynomial, EvaluationDomain},
+    polycommit::kzg10::KZG10,
+};
+use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+use snarkvm_utilities::Uniform;
+
+use rand::thread_rng;
+use serial_test::serial;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// The size in bytes above which an allocation is counted as large.
+const LARGE_ALLOCATION_SIZE: usize = 1 << 14;
+
+/// The number of large allocations made by all threads, including the threads of the rayon pool.
+static NUM_LARGE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// An allocator that counts the large allocations made by the process.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() >= LARGE_ALLOCATION_SIZE {
+            NUM_LARGE_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size >= LARGE_ALLOCATION_SIZE {
+            NUM_LARGE_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `f`, and returns the number of large allocations made by any thread in the meantime.
+///
+/// The count is process-wide, so the tests in this file are run serially.
+fn num_large_allocations(f: impl FnOnce()) -> usize {
+    let num_allocations_before = NUM_LARGE_ALLOCATIONS.load(Ordering::SeqCst);
+    f();
+    NUM_LARGE_ALLOCATIONS.load(Ordering::SeqCst) - num_allocations_before
+}
+
+#[test]
+#[serial]
+fn test_mul_with_scratch_does_not_allocate() {
+    let rng = &mut thread_rng();
+    let degree = (1 << 12) - 1;
+    let a = DensePolynomial::<Fr>::rand(degree, rng);
+    let b = DensePolynomial::<Fr>::rand(degree, rng);
+    let expected = &a * &b;
+
+    let mut scratch = MulScratch::<Fr>::new(2 * degree + 1).unwrap();
+    // Warm up the scratch space.
+    assert_eq!(a.mul_in_place_with_scratch(&b, &mut scratch).unwrap(), expected.coeffs());
+
+    let num_allocations = num_large_allocations(|| {
+        for _ in 0..10 {
+            assert_eq!(a.mul_in_place_with_scratch(&b, &mut scratch).unwrap(), expected.coeffs());
+        }
+    });
+    assert_eq!(num_allocations, 0);
+}
+
+#[test]
+#[serial]
+fn test_add_assign_scaled_reduces_allocations() {
+    let rng = &mut thread_rng();
+    let polynomials = (0..16).map(|_| DensePolynomial::<Fr>::rand((1 << 12) - 1, rng)).collect::<Vec<_>>();
+    let scalars = (0..16).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+    // Accumulate by allocating each scaled polynomial and each sum.
+    let mut expected = DensePolynomial::zero();
+    let num_allocations_with_copies = num_large_allocations(|| {
+        expected = polynomials
+            .iter()
+            .zip(&scalars)
+            .fold(DensePolynomial::zero(), |accumulator, (p, scalar)| &accumulator + &(p * *scalar));
+    });
+
+    // Accumulate in place.
+    let mut accumulator = DensePolynomial::zero();
+    let num_allocations_in_place = num_large_allocations(|| {
+        polynomials.iter().zip(&scalars).for_each(|(p, scalar)| accumulator.add_assign_scaled(p, *scalar));
+    });
+
+    assert_eq!(accumulator, expected);
+    // The coefficients of the accumulator are allocated once, rather than once per polynomial.
+    assert!(num_allocations_in_place <= 2, "Made {num_allocations_in_place} large allocations");
+    assert!(num_allocations_with_copies >= 2 * polynomials.len());
+}
+
+#[test]
+#[serial]
+fn test_shared_lagrange_basis_is_not_recomputed() {
+    let srs = KZG10::<Bls12_377>::load_srs(1 << 10).unwrap();
+    let domain = EvaluationDomain::<Fr>::new(1 << 8).unwrap();
+    let other_domain = EvaluationDomain::<Fr>::new(1 << 9).unwrap();
+    let expected = srs.lagrange_basis(domain).unwrap();
+
+    // The first call computes the basis.
+    let num_allocations = num_large_allocations(|| assert_eq!(*srs.shared_lagrange_basis(domain).unwrap(), expected));
+    assert!(num_allocations > 0);
+
+    // Subsequent calls over a domain of the same size reuse it.
+    let num_allocations = num_large_allocations(|| {
+        for _ in 0..10 {
+            assert_eq!(*srs.shared_lagrange_basis(domain).unwrap(), expected);
+        }
+    });
+    assert_eq!(num_allocations, 0);
+
+    // Only the most recent basis is kept, so switching domains recomputes it.
+    srs.shared_lagrange_basis(other_domain).unwrap();
+    let num_allocations = num_large_allocations(|| assert_eq!(*srs.shared_lagrange_basis(domain).unwrap(), expected));
+    assert!(num_allocations > 0);
+
+    // Clearing the cache releases the basis, without affecting the bases already handed out.
+    let basis = srs.shared_lagrange_basis(domain).unwrap();
+    assert_eq!(Arc::strong_count(&basis), 2);
+    srs.clear_lagrange_basis_cache();
+    assert_eq!(Arc::strong_count(&basis), 1);
+    assert_eq!(*basis, expected);
+
+    // The next call computes the basis again.
+    let num_allocations = num_large_allocations(|| assert_eq!(*srs.shared_lagrange_basis(domain).unwrap(), expected));
+    assert!(num_allocations > 0);
+}
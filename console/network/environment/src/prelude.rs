@@ -28,6 +28,7 @@ pub use snarkvm_utilities::{
     FromBits as _,
     FromBytes,
     FromBytesDeserializer,
+    IoResultExt,
     TestRng,
     ToBits as _,
     ToBytes,
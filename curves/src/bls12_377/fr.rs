@@ -118,6 +118,24 @@ impl FftParameters for FrParameters {
         268534165941069093u64,
         1121515446318641358u64,
     ]);
+    /// SMALL_SUBGROUP_BASE = 3
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
+    /// SMALL_SUBGROUP_BASE_ADICITY = 1
+    #[rustfmt::skip]
+    const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(1);
+    /// LARGE_SUBGROUP_ROOT_OF_UNITY = GENERATOR^((MODULUS - 1) / (2^47 * 3)) =
+    /// 4745010758872139845238200295841730218141082559516036141034422680643841032105
+    /// Encoded in Montgomery form, the value is
+    /// (4745010758872139845238200295841730218141082559516036141034422680643841032105 * R % q) =
+    /// 6305670270485671394103200713230422010059347173612490824610048904823110729716
+    #[rustfmt::skip]
+    const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
+        16312050644684472308u64,
+        16226885886700552844u64,
+        8981803609415491252u64,
+        1004551230217910552u64,
+    ]));
 }
 
 impl FieldParameters for FrParameters {
@@ -207,7 +225,7 @@ impl PoseidonDefaultParameters for FrParameters {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use snarkvm_fields::{FftField, Field, PrimeField};
+    use snarkvm_fields::{FftField, Field, One, PrimeField};
 
     #[test]
     fn test_powers_of_root_of_unity() {
@@ -237,4 +255,32 @@ mod tests {
         let expected = Fr::multiplicative_generator().pow(FrParameters::T);
         assert_eq!(expected, Fr::two_adic_root_of_unity());
     }
+
+    #[test]
+    fn test_large_subgroup_root_of_unity() {
+        let q = FrParameters::SMALL_SUBGROUP_BASE.unwrap() as u64;
+        let q_adicity = FrParameters::SMALL_SUBGROUP_BASE_ADICITY.unwrap();
+        let large_subgroup_root_of_unity = Fr::large_subgroup_root_of_unity().unwrap();
+
+        // Ensure the root of unity is consistent with the 2-adic root of unity.
+        let mut omega = large_subgroup_root_of_unity;
+        for _ in 0..q_adicity {
+            omega = omega.pow([q]);
+        }
+        assert_eq!(omega, Fr::two_adic_root_of_unity());
+
+        // Ensure the root of unity has order `2^TWO_ADICITY * q^q_adicity`.
+        let mut omega = large_subgroup_root_of_unity;
+        for _ in 0..FrParameters::TWO_ADICITY {
+            omega.square_in_place();
+        }
+        assert_ne!(omega, Fr::one());
+        assert_eq!(omega.pow([q.pow(q_adicity)]), Fr::one());
+
+        // Ensure roots of unity of mixed order are derived from it.
+        let root = Fr::get_root_of_unity(3 << 10).unwrap();
+        assert_eq!(root.pow([3 << 10]), Fr::one());
+        assert_ne!(root.pow([1 << 10]), Fr::one());
+        assert_ne!(root.pow([3 << 9]), Fr::one());
+    }
 }
@@ -17,7 +17,7 @@
 use snarkvm_algorithms::crypto_hash::sha256::sha256;
 use snarkvm_circuit::Aleo;
 use snarkvm_console::network::{Network, Testnet3};
-use snarkvm_synthesizer::{Process, Program};
+use snarkvm_synthesizer::{CoinbasePuzzle, Process, Program};
 
 use anyhow::Result;
 use serde_json::{json, Value};
@@ -147,6 +147,39 @@ pub fn credits_program<N: Network, A: Aleo<Network = N>>() -> Result<()> {
     Ok(())
 }
 
+/// Trims the coinbase puzzle keys from the universal SRS. (cargo run --release --example setup coinbase)
+pub fn coinbase_puzzle<N: Network>() -> Result<()> {
+    // Trim the coinbase puzzle keys to the degree of the network.
+    let puzzle = CoinbasePuzzle::<N>::load_from_srs()?;
+
+    let proving_key_bytes = puzzle.coinbase_proving_key()?.to_bytes_le()?;
+    let proving_key_checksum = checksum(&proving_key_bytes);
+
+    let verifying_key_bytes = puzzle.coinbase_verifying_key().to_bytes_le()?;
+    let verifying_key_checksum = checksum(&verifying_key_bytes);
+
+    let metadata = json!({
+        "degree": N::COINBASE_PUZZLE_DEGREE,
+        "prover_checksum": proving_key_checksum,
+        "prover_size": proving_key_bytes.len(),
+        "verifier_checksum": verifying_key_checksum,
+        "verifier_size": verifying_key_bytes.len(),
+    });
+
+    println!("{}", serde_json::to_string_pretty(&metadata)?);
+    write_metadata("coinbase.metadata", &metadata)?;
+    write_remote("coinbase.prover", &proving_key_checksum, &proving_key_bytes)?;
+    write_remote("coinbase.verifier", &verifying_key_checksum, &verifying_key_bytes)?;
+
+    // Print the commands.
+    println!("\nNow, run the following commands:\n");
+    println!("snarkup upload \"{}\"", versioned_filename("coinbase.prover", &proving_key_checksum));
+    println!("snarkup upload \"{}\"", versioned_filename("coinbase.verifier", &verifying_key_checksum));
+    println!();
+
+    Ok(())
+}
+
 /// Run the following command to perform a setup.
 /// `cargo run --example setup [variant]`
 pub fn main() -> Result<()> {
@@ -159,6 +192,7 @@ pub fn main() -> Result<()> {
     match args[1].as_str() {
         "usrs" => usrs()?,
         "credits" => credits_program::<Testnet3, snarkvm_circuit::AleoV0>()?,
+        "coinbase" => coinbase_puzzle::<Testnet3>()?,
         _ => panic!("Invalid parameter"),
     };
 
@@ -350,6 +350,32 @@ macro_rules! impl_remote {
                     expected_size
                 );
             }
+
+            /// Ensures the given bytes match the size and checksum in the metadata,
+            /// e.g. for bytes that were fetched without `load_bytes`.
+            pub fn verify_bytes(buffer: &[u8]) -> Result<(), $crate::errors::ParameterError> {
+                const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
+
+                let metadata: serde_json::Value =
+                    serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+
+                // Ensure the size matches.
+                if expected_size != buffer.len() {
+                    return Err($crate::errors::ParameterError::SizeMismatch(expected_size, buffer.len()));
+                }
+
+                // Ensure the checksum matches.
+                let candidate_checksum = checksum!(buffer);
+                if expected_checksum != candidate_checksum {
+                    return checksum_error!(expected_checksum, candidate_checksum);
+                }
+
+                Ok(())
+            }
         }
 
         paste::item! {
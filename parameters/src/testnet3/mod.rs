@@ -76,6 +76,23 @@ impl_remote!(SplitVerifier, REMOTE_URL, "resources/", "split", "verifier");
 impl_remote!(FeeProver, REMOTE_URL, "resources/", "fee", "prover");
 impl_remote!(FeeVerifier, REMOTE_URL, "resources/", "fee", "verifier");
 
+// Coinbase Puzzle
+impl_remote!(CoinbaseProvingKeyBytes, REMOTE_URL, "resources/", "coinbase", "prover");
+impl_remote!(CoinbaseVerifyingKeyBytes, REMOTE_URL, "resources/", "coinbase", "verifier");
+
+impl CoinbaseProvingKeyBytes {
+    /// Returns the degree of the coinbase puzzle that the coinbase proving key was trimmed to.
+    pub fn degree() -> u32 {
+        const METADATA: &str = include_str!("resources/coinbase.metadata");
+
+        let metadata: serde_json::Value = serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
+        metadata["degree"]
+            .as_u64()
+            .and_then(|degree| u32::try_from(degree).ok())
+            .expect("Failed to retrieve the degree")
+    }
+}
+
 #[macro_export]
 macro_rules! insert_credit_keys {
     ($map:ident, $type:ident<$network:ident>, $variant:ident) => {{
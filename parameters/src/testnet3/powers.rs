@@ -15,8 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use snarkvm_curves::traits::PairingEngine;
+use snarkvm_curves::traits::{AffineCurve, PairingEngine};
 use snarkvm_utilities::{
+    check_length_bounded,
+    deserialize_affine_vec,
     CanonicalDeserialize,
     CanonicalSerialize,
     Compress,
@@ -50,6 +52,18 @@ const NUM_POWERS_28: usize = 1 << 28;
 /// The maximum degree supported by the SRS.
 const MAX_NUM_POWERS: usize = NUM_POWERS_28;
 
+/// Deserializes a length-prefixed vector of at most `max_num_powers` group elements.
+fn deserialize_powers<G: AffineCurve, R: Read>(
+    mut reader: R,
+    max_num_powers: usize,
+    compress: Compress,
+    validate: Validate,
+) -> Result<Vec<G>, SerializationError> {
+    let num_powers = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+    let num_powers = check_length_bounded(num_powers, max_num_powers)?;
+    deserialize_affine_vec(reader, num_powers, compress, validate)
+}
+
 lazy_static::lazy_static! {
     static ref POWERS_OF_BETA_G_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
     static ref SHIFTED_POWERS_OF_BETA_G_15: Vec<u8> = ShiftedDegree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
@@ -192,7 +206,7 @@ impl<E: PairingEngine> ToBytes for PowersOfG<E> {
     }
 }
 
-#[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Debug, Clone, CanonicalSerialize)]
 pub struct PowersOfBetaG<E: PairingEngine> {
     /// Group elements of form `[G, \beta * G, \beta^2 * G, ..., \beta^d G]`.
     powers_of_beta_g: Vec<E::G1Affine>,
@@ -210,12 +224,13 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     /// Initializes the hard-coded instance of the powers.
     fn load() -> Result<Self> {
         // Deserialize the group elements.
-        let powers_of_beta_g = Vec::deserialize_uncompressed_unchecked(&**POWERS_OF_BETA_G_15)?;
+        let powers_of_beta_g = deserialize_powers(&**POWERS_OF_BETA_G_15, NUM_POWERS_15, Compress::No, Validate::No)?;
 
         // Ensure the number of elements is correct.
         ensure!(powers_of_beta_g.len() == NUM_POWERS_15, "Incorrect number of powers in the recovered SRS");
 
-        let shifted_powers_of_beta_g = Vec::deserialize_uncompressed_unchecked(&**SHIFTED_POWERS_OF_BETA_G_15)?;
+        let shifted_powers_of_beta_g =
+            deserialize_powers(&**SHIFTED_POWERS_OF_BETA_G_15, NUM_POWERS_15, Compress::No, Validate::No)?;
         ensure!(shifted_powers_of_beta_g.len() == NUM_POWERS_15, "Incorrect number of powers in the recovered SRS");
         Ok(PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g })
     }
@@ -388,7 +403,7 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             };
 
             // Deserialize the group elements.
-            let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
+            let additional_powers = deserialize_powers(&*additional_bytes, *num_powers, Compress::No, Validate::No)?;
             // Extend the powers.
             self.powers_of_beta_g.extend(&additional_powers);
         }
@@ -465,7 +480,7 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             };
 
             // Deserialize the group elements.
-            let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
+            let additional_powers = deserialize_powers(&*additional_bytes, *num_powers, Compress::No, Validate::No)?;
 
             if final_powers.is_empty() {
                 final_powers = additional_powers;
@@ -484,6 +499,25 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     }
 }
 
+impl<E: PairingEngine> CanonicalDeserialize for PowersOfBetaG<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let powers_of_beta_g = deserialize_powers(&mut reader, MAX_NUM_POWERS, compress, validate)?;
+        let shifted_powers_of_beta_g = deserialize_powers(&mut reader, MAX_NUM_POWERS, compress, validate)?;
+        Ok(Self { powers_of_beta_g, shifted_powers_of_beta_g })
+    }
+}
+
+impl<E: PairingEngine> Valid for PowersOfBetaG<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        self.powers_of_beta_g.check()?;
+        self.shifted_powers_of_beta_g.check()
+    }
+}
+
 impl<E: PairingEngine> FromBytes for PowersOfBetaG<E> {
     /// Reads the powers from the buffer.
     fn read_le<R: Read>(reader: R) -> std::io::Result<Self> {
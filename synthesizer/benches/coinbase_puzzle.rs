@@ -20,10 +20,19 @@
 extern crate criterion;
 
 use console::{account::*, network::Testnet3};
-use snarkvm_synthesizer::{CoinbasePuzzle, EpochChallenge, PuzzleConfig};
+use snarkvm_algorithms::polycommit::kzg10::{KZGCommitment, KZGProof};
+use snarkvm_synthesizer::{
+    CoinbasePreparedVerifyingKey,
+    CoinbasePuzzle,
+    CoinbaseSolution,
+    EpochChallenge,
+    PartialSolution,
+    PuzzleConfig,
+};
+use snarkvm_utilities::{FromBytes, ToBytes};
 
 use criterion::Criterion;
-use rand::{self, thread_rng, CryptoRng, RngCore};
+use rand::{self, thread_rng, CryptoRng, Rng, RngCore};
 
 type CoinbasePuzzleInst = CoinbasePuzzle<Testnet3>;
 
@@ -140,10 +149,87 @@ fn coinbase_puzzle_verify(c: &mut Criterion) {
     }
 }
 
+#[cfg(feature = "setup")]
+fn coinbase_puzzle_verify_prover_solutions(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let universal_srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    for degree in [(1 << 13) - 1] {
+        let config = PuzzleConfig { degree };
+        let puzzle = CoinbasePuzzleInst::trim(&universal_srs, config).unwrap();
+        let epoch_challenge = sample_epoch_challenge(degree, rng);
+        let verifying_key = puzzle.coinbase_verifying_key();
+
+        for batch_size in [10, 100, 500] {
+            let solutions = (0..batch_size)
+                .map(|_| {
+                    let (address, nonce) = sample_address_and_nonce(rng);
+                    puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            c.bench_function(
+                &format!("ProverSolution::Verify {batch_size} of 2^{}", ((degree + 1) as f64).log2()),
+                |b| {
+                    b.iter(|| {
+                        for solution in &solutions {
+                            assert!(solution.verify(verifying_key, &epoch_challenge, 0u64).unwrap());
+                        }
+                    })
+                },
+            );
+
+            c.bench_function(
+                &format!("ProverSolution::VerifyPrepared {batch_size} of 2^{}", ((degree + 1) as f64).log2()),
+                |b| {
+                    b.iter(|| {
+                        let prepared_verifying_key = CoinbasePreparedVerifyingKey::<Testnet3>::from(verifying_key);
+                        for solution in &solutions {
+                            assert!(solution.verify_prepared(&prepared_verifying_key, &epoch_challenge, 0u64).unwrap());
+                        }
+                    })
+                },
+            );
+        }
+    }
+}
+
+fn coinbase_solution_read_le(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    for num_solutions in [1_000, 10_000] {
+        let partial_solutions = (0..num_solutions)
+            .map(|_| {
+                let (address, nonce) = sample_address_and_nonce(rng);
+                PartialSolution::new(address, nonce, KZGCommitment(rng.gen()))
+            })
+            .collect::<Vec<_>>();
+        let partial_solutions_bytes = partial_solutions.iter().map(|s| s.to_bytes_le().unwrap()).collect::<Vec<_>>();
+        let solution = CoinbaseSolution::<Testnet3>::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+        let solution_bytes = solution.to_bytes_le().unwrap();
+
+        c.bench_function(&format!("PartialSolution::ReadLe {num_solutions} (per-point validation)"), |b| {
+            b.iter(|| {
+                for bytes in &partial_solutions_bytes {
+                    PartialSolution::<Testnet3>::read_le(&bytes[..]).unwrap();
+                }
+            })
+        });
+
+        c.bench_function(&format!("CoinbaseSolution::ReadLe {num_solutions} (batch validation)"), |b| {
+            b.iter(|| CoinbaseSolution::<Testnet3>::read_le(&solution_bytes[..]).unwrap())
+        });
+    }
+}
+
 criterion_group! {
     name = coinbase_puzzle;
     config = Criterion::default().sample_size(10);
     targets = coinbase_puzzle_trim, coinbase_puzzle_prove, coinbase_puzzle_accumulate, coinbase_puzzle_verify,
+        coinbase_puzzle_verify_prover_solutions, coinbase_solution_read_le,
 }
 
 criterion_main!(coinbase_puzzle);
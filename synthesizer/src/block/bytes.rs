@@ -21,28 +21,28 @@ impl<N: Network> FromBytes for Block<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
-        let version = u16::read_le(&mut reader)?;
+        let version = u16::read_le(&mut reader).field_context("version")?;
         // Ensure the version is valid.
         if version != 0 {
             return Err(error("Invalid block version"));
         }
 
         // Read the block.
-        let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
-        let previous_hash = FromBytes::read_le(&mut reader)?;
-        let header = FromBytes::read_le(&mut reader)?;
-        let transactions = FromBytes::read_le(&mut reader)?;
+        let block_hash: N::BlockHash = FromBytes::read_le(&mut reader).field_context("block_hash")?;
+        let previous_hash = FromBytes::read_le(&mut reader).field_context("previous_hash")?;
+        let header = FromBytes::read_le(&mut reader).field_context("header")?;
+        let transactions = FromBytes::read_le(&mut reader).field_context("transactions")?;
 
-        // Write the coinbase.
-        let coinbase_variant = u8::read_le(&mut reader)?;
+        // Read the coinbase.
+        let coinbase_variant = u8::read_le(&mut reader).field_context("coinbase")?;
         let coinbase = match coinbase_variant {
             0 => None,
-            1 => Some(FromBytes::read_le(&mut reader)?),
+            1 => Some(FromBytes::read_le(&mut reader).field_context("coinbase")?),
             _ => return Err(error("Invalid coinbase variant")),
         };
 
-        // Write the signature.
-        let signature = FromBytes::read_le(&mut reader)?;
+        // Read the signature.
+        let signature = FromBytes::read_le(&mut reader).field_context("signature")?;
 
         // Construct the block.
         let block =
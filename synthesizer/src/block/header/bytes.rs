@@ -21,17 +21,18 @@ impl<N: Network> FromBytes for Header<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
-        let version = u16::read_le(&mut reader)?;
+        let version = u16::read_le(&mut reader).field_context("version")?;
         // Ensure the version is valid.
         if version != 0 {
             return Err(error("Invalid header version"));
         }
 
         // Read from the buffer.
-        let previous_state_root = Field::<N>::read_le(&mut reader)?;
-        let transactions_root = Field::<N>::read_le(&mut reader)?;
-        let coinbase_accumulator_point = Field::<N>::read_le(&mut reader)?;
-        let metadata = Metadata::read_le(&mut reader)?;
+        let previous_state_root = Field::<N>::read_le(&mut reader).field_context("previous_state_root")?;
+        let transactions_root = Field::<N>::read_le(&mut reader).field_context("transactions_root")?;
+        let coinbase_accumulator_point =
+            Field::<N>::read_le(&mut reader).field_context("coinbase_accumulator_point")?;
+        let metadata = Metadata::read_le(&mut reader).field_context("metadata")?;
 
         // Construct the block header.
         Self::from(previous_state_root, transactions_root, coinbase_accumulator_point, metadata)
@@ -21,15 +21,18 @@ impl<N: Network> FromBytes for Transactions<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the version.
-        let version = u16::read_le(&mut reader)?;
+        let version = u16::read_le(&mut reader).field_context("version")?;
         // Ensure the version is valid.
         if version != 0 {
             return Err(error("Invalid header version"));
         }
         // Read the number of transactions.
-        let num_txs: u32 = FromBytes::read_le(&mut reader)?;
+        let num_txs: u32 = FromBytes::read_le(&mut reader).field_context("transactions")?;
         // Read the transactions.
-        let transactions = (0..num_txs).map(|_| FromBytes::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        let transactions = (0..num_txs as usize)
+            .map(|index| FromBytes::read_le(&mut reader).index_context(index))
+            .collect::<Result<Vec<_>, _>>()
+            .field_context("transactions")?;
         // Return the transactions.
         Ok(Self::from(&transactions))
     }
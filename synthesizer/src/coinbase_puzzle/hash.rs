@@ -14,8 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use console::prelude::{bail, cfg_into_iter, ensure, Result, Zero};
-use snarkvm_algorithms::{fft::DensePolynomial, polycommit::kzg10::KZGCommitment};
+use console::prelude::{anyhow, bail, cfg_into_iter, ensure, Result, Zero};
+use snarkvm_algorithms::{
+    fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
+    polycommit::kzg10::KZGCommitment,
+};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::PrimeField;
 use snarkvm_utilities::CanonicalSerialize;
@@ -46,6 +49,21 @@ pub fn hash_to_polynomial<F: PrimeField>(input: &[u8], degree: u32) -> DensePoly
     DensePolynomial::from_coefficients_vec(coefficients)
 }
 
+/// Hashes the input into the evaluations over `domain` of the polynomial returned by `hash_to_polynomial`,
+/// so that interpolating the evaluations recovers the coefficients of that polynomial.
+pub fn hash_to_polynomial_evaluations<F: PrimeField>(
+    input: &[u8],
+    degree: u32,
+    domain: EvaluationDomain<F>,
+) -> Result<EvaluationsOnDomain<F>> {
+    let num_coefficients = degree.checked_add(1).ok_or_else(|| anyhow!("Degree is too large"))?;
+    ensure!(num_coefficients as usize <= domain.size(), "The domain is too small for a polynomial of degree {degree}");
+    // Hash the input into coefficients, and evaluate them over the domain.
+    let mut evaluations = hash_to_coefficients(input, num_coefficients);
+    domain.fft_in_place(&mut evaluations);
+    Ok(EvaluationsOnDomain::from_vec_and_domain(evaluations, domain))
+}
+
 pub fn hash_commitment<E: PairingEngine>(commitment: &KZGCommitment<E>) -> Result<E::Fr> {
     // Convert the commitment into bytes.
     let mut bytes = Vec::with_capacity(96);
@@ -19,37 +19,40 @@ use super::*;
 impl<N: Network> FromBytes for CoinbaseSolution<N> {
     /// Reads the coinbase solution from the buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let partial_solutions_len: u32 = FromBytes::read_le(&mut reader)?;
+        let partial_solutions_len: u32 = FromBytes::read_le(&mut reader).field_context("partial_solutions")?;
+        let partial_solutions_len =
+            check_length_bounded(partial_solutions_len, N::MAX_PROVER_SOLUTIONS).field_context("partial_solutions")?;
 
-        let mut partial_solutions = Vec::with_capacity(partial_solutions_len as usize);
-        for _ in 0..partial_solutions_len {
-            let individual_puzzle_solution: PartialSolution<N> = FromBytes::read_le(&mut reader)?;
-            partial_solutions.push(individual_puzzle_solution);
-        }
-
-        let proof = KZGProof::read_le(&mut reader)?;
+        // The partial solutions are collected as they are read, so a forged length does not allocate up front.
+        let partial_solutions = (0..partial_solutions_len)
+            .map(|index| PartialSolution::<N>::read_le_unchecked(&mut reader).index_context(index))
+            .collect::<IoResult<Vec<_>>>()
+            .field_context("partial_solutions")?;
 
-        Ok(Self::new(partial_solutions, proof))
-    }
-}
+        // Ensure all of the commitments are in the correct subgroup, checking them in a single batch.
+        let commitments = partial_solutions.iter().map(|solution| *solution.commitment()).collect::<Vec<_>>();
+        KZGCommitment::batch_validate(&commitments)
+            .map_err(|e| error(e.to_string()))
+            .field_context("partial_solutions")?;
 
-impl<N: Network> ToBytes for CoinbaseSolution<N> {
-    /// Writes the coinbase solution to the buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        (self.partial_solutions.len() as u32).write_le(&mut writer)?;
+        let proof = KZGProof::read_le(&mut reader).field_context("proof")?;
 
-        for individual_puzzle_solution in &self.partial_solutions {
-            individual_puzzle_solution.write_le(&mut writer)?;
-        }
-
-        self.proof.write_le(&mut writer)
+        Ok(Self::new(partial_solutions, proof))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{account::PrivateKey, network::Testnet3};
+    use console::{
+        account::{Group, PrivateKey},
+        network::Testnet3,
+    };
+    use snarkvm_curves::{
+        bls12_377::{Fq, G1Affine},
+        AffineCurve,
+    };
+    use snarkvm_utilities::{to_bytes_le, CanonicalBytes, Compress, FromHex, ToHex};
 
     type CurrentNetwork = Testnet3;
 
@@ -65,13 +68,174 @@ mod tests {
 
             partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
         }
-        let expected = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+        let proof = KZGProof { w: rng.gen(), random_v: None };
+        let expected = CoinbaseSolution::new(partial_solutions.clone(), proof);
 
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes, to_bytes_le![partial_solutions.len() as u32, partial_solutions, proof]?);
         assert_eq!(expected, CoinbaseSolution::read_le(&expected_bytes[..])?);
         // assert!(CoinbaseSolution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
 
         Ok(())
     }
+
+    #[test]
+    fn test_length_bound() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let partial_solution =
+            PartialSolution::new(Address::try_from(private_key)?, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+
+        // Ensure a length prefix above the bound is rejected before any partial solution is read.
+        for length in [CurrentNetwork::MAX_PROVER_SOLUTIONS as u32 + 1, u32::MAX] {
+            let bytes = to_bytes_le![length, partial_solution]?;
+            let error = CoinbaseSolution::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+            let bound = CurrentNetwork::MAX_PROVER_SOLUTIONS;
+            let expected = format!("partial_solutions: Length {length} exceeds the bound of {bound}");
+            assert_eq!(error.to_string(), expected);
+        }
+
+        // Ensure a length prefix within the bound fails once the input runs out.
+        let bytes = to_bytes_le![CurrentNetwork::MAX_PROVER_SOLUTIONS as u32, partial_solution]?;
+        assert!(CoinbaseSolution::<CurrentNetwork>::read_le(&bytes[..]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_context() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new coinbase solution with 10 partial solutions.
+        let mut partial_solutions = vec![];
+        for _ in 0..10 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+        }
+        let proof = KZGProof { w: rng.gen(), random_v: None };
+        let bytes = CoinbaseSolution::<CurrentNetwork>::new(partial_solutions, proof).to_bytes_le()?;
+
+        // Each partial solution is an address (32 bytes), a nonce (8 bytes), and a compressed commitment (48 bytes).
+        let commitment_offset = |index: usize| 4 + index * (32 + 8 + 48) + 32 + 8;
+
+        // Corrupt the encoding of the commitment in the 8th partial solution.
+        let mut corrupted = bytes.clone();
+        corrupted[commitment_offset(7)..commitment_offset(7) + 48].fill(0xFF);
+        let error = CoinbaseSolution::<CurrentNetwork>::read_le(&corrupted[..]).unwrap_err();
+        assert_eq!(error.to_string(), "partial_solutions[7].commitment: could not deserialize KZGCommitment");
+
+        // Truncate the input within the nonce of the 4th partial solution.
+        let error = CoinbaseSolution::<CurrentNetwork>::read_le(&bytes[..commitment_offset(3) - 1]).unwrap_err();
+        assert!(error.to_string().starts_with("partial_solutions[3].nonce: "), "{error}");
+
+        // Truncate the input within the proof.
+        let error = CoinbaseSolution::<CurrentNetwork>::read_le(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert!(error.to_string().starts_with("proof: "), "{error}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_size() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new coinbase solution with 1000 partial solutions.
+        let mut partial_solutions = vec![];
+        for _ in 0..1000 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+        }
+        let proof = KZGProof { w: rng.gen(), random_v: None };
+        let expected = CoinbaseSolution::new(partial_solutions, proof);
+
+        // Compute the size of the solution with uncompressed commitments and proof.
+        let mut uncompressed_size = 4 + 1000 * (32 + 8);
+        for partial_solution in expected.partial_solutions() {
+            let mut bytes = vec![];
+            partial_solution.commitment().write_le_with(&mut bytes, Compress::No)?;
+            uncompressed_size += bytes.len();
+        }
+        let mut bytes = vec![];
+        proof.write_le_with(&mut bytes, Compress::No)?;
+        uncompressed_size += bytes.len();
+
+        // Ensure the points are compressed, which saves 48 bytes per point.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes.len(), 88_053);
+        assert_eq!(uncompressed_size, 136_101);
+        assert_eq!(expected_bytes.len() + 1001 * 48, uncompressed_size);
+        assert_eq!(expected, CoinbaseSolution::read_le(&expected_bytes[..])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_commitment_is_rejected() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a point on the curve that is not in the prime-order subgroup.
+        let invalid_point = loop {
+            if let Some(point) = G1Affine::from_x_coordinate(Fq::rand(&mut rng), true) {
+                if !point.is_in_correct_subgroup_assuming_on_curve() {
+                    break point;
+                }
+            }
+        };
+
+        // Sample a coinbase solution with a single invalid commitment.
+        let invalid_index = 7;
+        let mut partial_solutions = vec![];
+        for i in 0..10 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+            let commitment = if i == invalid_index { KZGCommitment(invalid_point) } else { KZGCommitment(rng.gen()) };
+
+            partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), commitment));
+        }
+        let solution =
+            CoinbaseSolution::<CurrentNetwork>::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None });
+
+        // Ensure the batch check catches the invalid commitment, and reports its index.
+        let bytes = solution.to_bytes_le()?;
+        let error = CoinbaseSolution::<CurrentNetwork>::read_le(&bytes[..]).unwrap_err();
+        assert!(error.to_string().contains(&format!("Commitment {invalid_index}")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pinned_bytes() -> Result<()> {
+        // Construct a coinbase solution from fixed values, so the encoding is deterministic.
+        let address = Address::<CurrentNetwork>::new(Group::generator());
+        let commitment = KZGCommitment(G1Affine::prime_subgroup_generator());
+        let partial_solutions =
+            vec![PartialSolution::new(address, 0, commitment), PartialSolution::new(address, 1, commitment)];
+        let proof = KZGProof { w: G1Affine::prime_subgroup_generator(), random_v: None };
+        let expected = CoinbaseSolution::new(partial_solutions, proof);
+
+        // Ensure the encoding matches the byte layout of the original implementation.
+        let expected_hex = concat!(
+            // The number of partial solutions.
+            "02000000",
+            // The partial solutions.
+            "c549be4b84827e13f383dda9338860e70650900db894b21607504702eb246803",
+            "0000000000000000",
+            "66db6f1030f91f0f1824381daad6321c43739c828b3d8d69f75711a97a2d524c3d54201c61a85ca1b3835901b6a29400",
+            "c549be4b84827e13f383dda9338860e70650900db894b21607504702eb246803",
+            "0100000000000000",
+            "66db6f1030f91f0f1824381daad6321c43739c828b3d8d69f75711a97a2d524c3d54201c61a85ca1b3835901b6a29400",
+            // The compressed proof, followed by the tag for the absent `random_v`.
+            "66db6f1030f91f0f1824381daad6321c43739c828b3d8d69f75711a97a2d524c3d54201c61a85ca1b3835901b6a29400",
+            "00",
+        );
+        assert_eq!(expected.to_hex()?, expected_hex);
+        assert_eq!(expected, CoinbaseSolution::from_hex(expected_hex)?);
+
+        Ok(())
+    }
 }
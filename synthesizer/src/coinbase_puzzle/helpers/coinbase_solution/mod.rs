@@ -21,7 +21,7 @@ mod string;
 use super::*;
 
 /// The coinbase puzzle solution constructed by accumulating the individual prover solutions.
-#[derive(Clone, Eq, PartialEq, Hash)]
+#[derive(Clone, Eq, PartialEq, Hash, ToBytes)]
 pub struct CoinbaseSolution<N: Network> {
     /// The partial solutions of the coinbase puzzle, which are aggregated into a single solution.
     partial_solutions: Vec<PartialSolution<N>>,
@@ -21,7 +21,8 @@ impl<N: Network> Serialize for CoinbaseSolution<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut combined_puzzle_solution = serializer.serialize_struct("CoinbaseSolution", 3)?;
+                let num_fields = 2 + self.proof.random_v.is_some() as usize;
+                let mut combined_puzzle_solution = serializer.serialize_struct("CoinbaseSolution", num_fields)?;
                 combined_puzzle_solution.serialize_field("partial_solutions", &self.partial_solutions)?;
                 combined_puzzle_solution.serialize_field("proof.w", &self.proof.w)?;
                 if let Some(random_v) = &self.proof.random_v {
@@ -64,6 +65,7 @@ impl<'de, N: Network> Deserialize<'de> for CoinbaseSolution<N> {
 mod tests {
     use super::*;
     use console::{account::PrivateKey, network::Testnet3};
+    use snarkvm_utilities::assert_serde_consistency;
 
     type CurrentNetwork = Testnet3;
 
@@ -118,4 +120,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_serde_consistency() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for random_v in [None, Some(rng.gen())] {
+            let mut partial_solutions = vec![];
+            for _ in 0..rng.gen_range(1..10) {
+                let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+                let address = Address::try_from(private_key)?;
+
+                partial_solutions.push(PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen())));
+            }
+            let coinbase_solution = CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v });
+            assert_serde_consistency!(CoinbaseSolution<CurrentNetwork>, coinbase_solution);
+        }
+
+        Ok(())
+    }
 }
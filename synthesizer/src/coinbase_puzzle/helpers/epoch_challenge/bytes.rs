@@ -20,11 +20,11 @@ impl<N: Network> FromBytes for EpochChallenge<N> {
     /// Reads the epoch challenge from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the epoch number.
-        let epoch_number = FromBytes::read_le(&mut reader)?;
+        let epoch_number = FromBytes::read_le(&mut reader).field_context("epoch_number")?;
         // Read the epoch block hash.
-        let epoch_block_hash = FromBytes::read_le(&mut reader)?;
+        let epoch_block_hash = FromBytes::read_le(&mut reader).field_context("epoch_block_hash")?;
         // Read the epoch degree.
-        let degree = FromBytes::read_le(&mut reader)?;
+        let degree = FromBytes::read_le(&mut reader).field_context("degree")?;
         // Return the epoch challenge.
         Self::new(epoch_number, epoch_block_hash, degree).map_err(|e| error(e.to_string()))
     }
@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod bytes;
+mod serialize;
 
 use snarkvm_algorithms::fft::Evaluations as EvaluationsOnDomain;
 
@@ -64,6 +65,11 @@ impl<N: Network> EpochChallenge<N> {
         &self.epoch_polynomial
     }
 
+    /// Returns the product domain, over which the epoch polynomial is evaluated.
+    pub fn product_domain(&self) -> EvaluationDomain<<N::PairingCurve as PairingEngine>::Fr> {
+        self.epoch_polynomial_evaluations.domain()
+    }
+
     /// Returns the evaluations of the epoch polynomial over the product domain.
     pub const fn epoch_polynomial_evaluations(&self) -> &EvaluationsOnDomain<<N::PairingCurve as PairingEngine>::Fr> {
         &self.epoch_polynomial_evaluations
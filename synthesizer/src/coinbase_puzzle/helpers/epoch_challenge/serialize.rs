@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Serialize for EpochChallenge<N> {
+    /// Serializes the epoch challenge to a hex string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize_hex_or_bytes(serializer)
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for EpochChallenge<N> {
+    /// Deserializes the epoch challenge from a hex string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::deserialize_hex_or_bytes(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use snarkvm_utilities::assert_serde_consistency;
+
+    use rand::RngCore;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new epoch challenge.
+        let expected = EpochChallenge::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), 1 << 10)?;
+
+        // Serialize
+        let candidate_string = serde_json::to_string(&expected)?;
+        let expected_hex = expected.to_bytes_le()?.iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        assert_eq!(candidate_string, format!("\"{expected_hex}\""));
+
+        // Deserialize
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new epoch challenge.
+        let expected = EpochChallenge::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), 1 << 10)?;
+
+        // Serialize
+        let expected_bytes = expected.to_bytes_le()?;
+        let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+        assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+        // Deserialize
+        assert_eq!(expected, EpochChallenge::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_consistency() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for degree in [1 << 5, 1 << 8, 1 << 10] {
+            let epoch_challenge = EpochChallenge::<CurrentNetwork>::new(rng.next_u32(), rng.gen(), degree)?;
+            assert_serde_consistency!(EpochChallenge<CurrentNetwork>, epoch_challenge);
+        }
+
+        Ok(())
+    }
+}
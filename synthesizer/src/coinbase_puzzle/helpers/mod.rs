@@ -33,15 +33,25 @@ use crate::coinbase_puzzle::{hash_commitment, hash_commitments, CoinbasePuzzle};
 use console::{account::Address, prelude::*, types::Field};
 use snarkvm_algorithms::{
     fft::{domain::FFTPrecomputation, DensePolynomial, EvaluationDomain},
-    polycommit::kzg10::{KZGCommitment, KZGProof, LagrangeBasis, VerifierKey, KZG10},
+    polycommit::kzg10::{KZGCommitment, KZGProof, LagrangeBasis, PreparedVerifierKey, VerifierKey, KZG10},
 };
 use snarkvm_curves::PairingEngine;
-use snarkvm_utilities::{FromBytes, ToBytes};
+use snarkvm_utilities::{
+    check_length_bounded,
+    deserialize_affine_vec,
+    CanonicalSerialize,
+    Compress,
+    FromBytes,
+    SerializeExt,
+    ToBytes,
+    Validate,
+};
 
 use anyhow::Result;
 use std::{
     borrow::Cow,
     io::{Read, Result as IoResult, Write},
+    sync::Arc,
 };
 
 /// The proof of opening the polynomial, for the solution.
@@ -55,10 +65,12 @@ pub struct PuzzleConfig {
 
 pub type CoinbaseVerifyingKey<N> = VerifierKey<<N as Environment>::PairingCurve>;
 
+pub type CoinbasePreparedVerifyingKey<N> = PreparedVerifierKey<<N as Environment>::PairingCurve>;
+
 #[derive(Clone, Debug)]
 pub struct CoinbaseProvingKey<N: Network> {
-    /// The key used to commit to polynomials in Lagrange basis.
-    pub lagrange_basis_at_beta_g: Vec<<N::PairingCurve as PairingEngine>::G1Affine>,
+    /// The key used to commit to polynomials in Lagrange basis, shared with the SRS it was trimmed from.
+    pub lagrange_basis_at_beta_g: Arc<Vec<<N::PairingCurve as PairingEngine>::G1Affine>>,
     /// Domain used to compute the product of the epoch polynomial and the prover polynomial.
     pub product_domain: EvaluationDomain<<N::PairingCurve as PairingEngine>::Fr>,
     /// Precomputation to speed up FFTs.
@@ -84,3 +96,55 @@ impl<N: Network> CoinbaseProvingKey<N> {
         &self.product_domain_elements
     }
 }
+
+impl<N: Network> FromBytes for CoinbaseProvingKey<N> {
+    /// Reads the coinbase proving key from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u16::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid coinbase proving key version"));
+        }
+        // Read the size of the product domain.
+        let product_domain_size = u32::read_le(&mut reader)?;
+        let product_domain = EvaluationDomain::new(product_domain_size as usize)
+            .filter(|domain| domain.size() == product_domain_size as usize)
+            .ok_or_else(|| error("Invalid coinbase product domain size"))?;
+        // Read the length of the Lagrange basis, and ensure it matches the product domain before reading any point.
+        let lagrange_basis_len = u64::read_le(&mut reader)?;
+        if lagrange_basis_len != product_domain.size() as u64 {
+            return Err(error("The coinbase Lagrange basis does not match the product domain"));
+        }
+        // Read the Lagrange basis as one block, validating the points in parallel.
+        let lagrange_basis_at_beta_g: Vec<<N::PairingCurve as PairingEngine>::G1Affine> =
+            deserialize_affine_vec(&mut reader, product_domain.size(), Compress::Yes, Validate::Yes)
+                .map_err(|_| error("could not deserialize the coinbase Lagrange basis"))?;
+        // Read the verifying key.
+        let verifying_key = CoinbaseVerifyingKey::<N>::read_le(&mut reader)?;
+
+        Ok(Self {
+            lagrange_basis_at_beta_g: Arc::new(lagrange_basis_at_beta_g),
+            product_domain,
+            fft_precomputation: product_domain.precompute_fft(),
+            product_domain_elements: product_domain.elements().collect(),
+            verifying_key,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for CoinbaseProvingKey<N> {
+    /// Writes the coinbase proving key to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        0u16.write_le(&mut writer)?;
+        // Write the size of the product domain.
+        u32::try_from(self.product_domain.size()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        // Write the Lagrange basis.
+        self.lagrange_basis_at_beta_g
+            .serialize_compressed(&mut writer)
+            .map_err(|_| error("could not serialize the coinbase Lagrange basis"))?;
+        // Write the verifying key.
+        self.verifying_key.write_le(&mut writer)
+    }
+}
@@ -16,30 +16,27 @@
 
 use super::*;
 
-impl<N: Network> FromBytes for PartialSolution<N> {
-    /// Reads the partial solution from the buffer.
-    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let address: Address<N> = FromBytes::read_le(&mut reader)?;
-        let nonce = u64::read_le(&mut reader)?;
-        let commitment = KZGCommitment::read_le(&mut reader)?;
+impl<N: Network> PartialSolution<N> {
+    /// Reads the partial solution from the buffer, without checking that the commitment is in the correct subgroup.
+    /// The caller must validate the commitment, e.g. with `KZGCommitment::batch_validate`, before using it.
+    pub(crate) fn read_le_unchecked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let address: Address<N> = FromBytes::read_le(&mut reader).field_context("address")?;
+        let nonce = u64::read_le(&mut reader).field_context("nonce")?;
+        let commitment = KZGCommitment::read_le_unchecked(&mut reader).field_context("commitment")?;
 
         Ok(Self::new(address, nonce, commitment))
     }
 }
 
-impl<N: Network> ToBytes for PartialSolution<N> {
-    /// Writes the partial solution to the buffer.
-    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        self.address.write_le(&mut writer)?;
-        self.nonce.write_le(&mut writer)?;
-        self.commitment.write_le(&mut writer)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{account::PrivateKey, network::Testnet3};
+    use console::{
+        account::{Group, PrivateKey},
+        network::Testnet3,
+    };
+    use snarkvm_curves::{bls12_377::G1Affine, AffineCurve};
+    use snarkvm_utilities::{to_bytes_le, FromHex, ToHex};
 
     type CurrentNetwork = Testnet3;
 
@@ -50,13 +47,39 @@ mod tests {
         let address = Address::try_from(private_key)?;
 
         // Sample a new partial solution.
-        let expected = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+        let nonce = u64::rand(&mut rng);
+        let commitment = KZGCommitment(rng.gen());
+        let expected = PartialSolution::new(address, nonce, commitment);
 
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes, to_bytes_le![address, nonce, commitment]?);
         assert_eq!(expected, PartialSolution::read_le(&expected_bytes[..])?);
+        assert_eq!(expected, PartialSolution::read_le_unchecked(&expected_bytes[..])?);
         assert!(PartialSolution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
 
         Ok(())
     }
+
+    #[test]
+    fn test_pinned_bytes() -> Result<()> {
+        // Construct a partial solution from fixed values, so the encoding is deterministic.
+        let address = Address::<CurrentNetwork>::new(Group::generator());
+        let commitment = KZGCommitment(G1Affine::prime_subgroup_generator());
+        let expected = PartialSolution::new(address, 0x0123_4567_89ab_cdef, commitment);
+
+        // Ensure the encoding matches the byte layout of the original implementation.
+        let expected_hex = concat!(
+            // The address.
+            "c549be4b84827e13f383dda9338860e70650900db894b21607504702eb246803",
+            // The nonce.
+            "efcdab8967452301",
+            // The compressed commitment.
+            "66db6f1030f91f0f1824381daad6321c43739c828b3d8d69f75711a97a2d524c3d54201c61a85ca1b3835901b6a29400",
+        );
+        assert_eq!(expected.to_hex()?, expected_hex);
+        assert_eq!(expected, PartialSolution::from_hex(expected_hex)?);
+
+        Ok(())
+    }
 }
@@ -22,7 +22,7 @@ use super::*;
 use snarkvm_algorithms::crypto_hash::sha256d_to_u64;
 
 /// The partial solution for the coinbase puzzle from a prover.
-#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, ToBytes, FromBytes)]
 pub struct PartialSolution<N: Network> {
     /// The address of the prover.
     address: Address<N>,
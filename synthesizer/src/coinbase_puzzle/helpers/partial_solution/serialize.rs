@@ -54,6 +54,7 @@ impl<'de, N: Network> Deserialize<'de> for PartialSolution<N> {
 mod tests {
     use super::*;
     use console::{account::PrivateKey, network::Testnet3};
+    use snarkvm_utilities::assert_serde_consistency;
 
     type CurrentNetwork = Testnet3;
 
@@ -98,4 +99,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_serde_consistency() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            let partial_solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+            assert_serde_consistency!(PartialSolution<CurrentNetwork>, partial_solution);
+        }
+
+        Ok(())
+    }
 }
@@ -19,8 +19,8 @@ use super::*;
 impl<N: Network> FromBytes for ProverSolution<N> {
     /// Reads the prover solution from the buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let partial_solution: PartialSolution<N> = FromBytes::read_le(&mut reader)?;
-        let proof = KZGProof::read_le(&mut reader)?;
+        let partial_solution: PartialSolution<N> = FromBytes::read_le(&mut reader).field_context("partial_solution")?;
+        let proof = KZGProof::read_le(&mut reader).field_context("proof")?;
 
         Ok(Self::new(partial_solution, proof))
     }
@@ -37,7 +37,12 @@ impl<N: Network> ToBytes for ProverSolution<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{account::PrivateKey, network::Testnet3};
+    use console::{
+        account::{Group, PrivateKey},
+        network::Testnet3,
+    };
+    use snarkvm_curves::{bls12_377::G1Affine, AffineCurve};
+    use snarkvm_utilities::{to_bytes_le, FromHex, ToHex};
 
     type CurrentNetwork = Testnet3;
 
@@ -49,13 +54,40 @@ mod tests {
 
         // Sample a new prover solution.
         let partial_solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
-        let expected = ProverSolution::new(partial_solution, KZGProof { w: rng.gen(), random_v: None });
+        let proof = KZGProof { w: rng.gen(), random_v: None };
+        let expected = ProverSolution::new(partial_solution, proof);
 
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected_bytes, to_bytes_le![partial_solution, proof]?);
         assert_eq!(expected, ProverSolution::read_le(&expected_bytes[..])?);
         assert!(ProverSolution::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
 
         Ok(())
     }
+
+    #[test]
+    fn test_pinned_bytes() -> Result<()> {
+        // Construct a prover solution from fixed values, so the encoding is deterministic.
+        let address = Address::<CurrentNetwork>::new(Group::generator());
+        let commitment = KZGCommitment(G1Affine::prime_subgroup_generator());
+        let partial_solution = PartialSolution::new(address, 0x0123_4567_89ab_cdef, commitment);
+        let proof = KZGProof { w: G1Affine::prime_subgroup_generator(), random_v: None };
+        let expected = ProverSolution::new(partial_solution, proof);
+
+        // Ensure the encoding matches the byte layout of the original implementation.
+        let expected_hex = concat!(
+            // The partial solution.
+            "c549be4b84827e13f383dda9338860e70650900db894b21607504702eb246803",
+            "efcdab8967452301",
+            "66db6f1030f91f0f1824381daad6321c43739c828b3d8d69f75711a97a2d524c3d54201c61a85ca1b3835901b6a29400",
+            // The compressed proof, followed by the tag for the absent `random_v`.
+            "66db6f1030f91f0f1824381daad6321c43739c828b3d8d69f75711a97a2d524c3d54201c61a85ca1b3835901b6a29400",
+            "00",
+        );
+        assert_eq!(expected.to_hex()?, expected_hex);
+        assert_eq!(expected, ProverSolution::from_hex(expected_hex)?);
+
+        Ok(())
+    }
 }
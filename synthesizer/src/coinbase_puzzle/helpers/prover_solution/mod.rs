@@ -36,17 +36,55 @@ impl<N: Network> ProverSolution<N> {
     }
 
     /// Returns `true` if the prover solution is valid.
+    ///
+    /// Returns an error if the prover solution does not meet the proof target, or if its proof is hiding.
+    /// Coinbase proofs are never hiding, so a hiding proof is rejected as an error rather than returning `false`,
+    /// provided the verifying key has no hiding generator, as ensured by `CoinbasePuzzle::trim`
+    /// and `CoinbasePuzzle::from_parameter_bytes`.
     pub fn verify(
         &self,
         verifying_key: &CoinbaseVerifyingKey<N>,
         epoch_challenge: &EpochChallenge<N>,
         proof_target: u64,
     ) -> Result<bool> {
-        // Ensure the proof is non-hiding.
-        if self.proof.is_hiding() {
-            return Ok(false);
-        }
+        // Compute the challenge point and the claimed value.
+        let (challenge_point, claimed_value) = self.to_challenge_point_and_value(epoch_challenge, proof_target)?;
+
+        // Check the KZG proof.
+        Ok(KZG10::check(verifying_key, &self.commitment(), challenge_point, claimed_value, self.proof())?)
+    }
+
+    /// Returns `true` if the prover solution is valid, using a prepared verifying key.
+    ///
+    /// This method should be preferred over `verify` when checking many prover solutions.
+    /// It returns an error in the same cases as `verify`, including for a hiding proof.
+    pub fn verify_prepared(
+        &self,
+        prepared_verifying_key: &CoinbasePreparedVerifyingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<bool> {
+        // Compute the challenge point and the claimed value.
+        let (challenge_point, claimed_value) = self.to_challenge_point_and_value(epoch_challenge, proof_target)?;
 
+        // Check the KZG proof.
+        Ok(KZG10::check_prepared(
+            prepared_verifying_key,
+            &self.commitment(),
+            challenge_point,
+            claimed_value,
+            self.proof(),
+        )?)
+    }
+
+    /// Returns the challenge point and the claimed evaluation of the product polynomial at that point.
+    ///
+    /// Hiding proofs are rejected by the KZG check, as the coinbase verifying key has no hiding generator.
+    pub(crate) fn to_challenge_point_and_value(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<(<N::PairingCurve as PairingEngine>::Fr, <N::PairingCurve as PairingEngine>::Fr)> {
         // Ensure that the prover solution is greater than the proof target.
         if self.to_target()? < proof_target {
             bail!("Prover puzzle does not meet the proof target requirements.")
@@ -63,10 +101,7 @@ impl<N: Network> ProverSolution<N> {
         let prover_evaluation = prover_polynomial.evaluate(challenge_point);
 
         // Compute the claimed value by multiplying the evaluations.
-        let claimed_value = epoch_evaluation * prover_evaluation;
-
-        // Check the KZG proof.
-        Ok(KZG10::check(verifying_key, &self.commitment(), challenge_point, claimed_value, self.proof())?)
+        Ok((challenge_point, epoch_evaluation * prover_evaluation))
     }
 
     /// Returns the address of the prover.
@@ -21,7 +21,8 @@ impl<N: Network> Serialize for ProverSolution<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut prover_solution = serializer.serialize_struct("ProverSolution", 3)?;
+                let num_fields = 2 + self.proof.random_v.is_some() as usize;
+                let mut prover_solution = serializer.serialize_struct("ProverSolution", num_fields)?;
                 prover_solution.serialize_field("partial_solution", &self.partial_solution)?;
                 prover_solution.serialize_field("proof.w", &self.proof.w)?;
                 if let Some(random_v) = &self.proof.random_v {
@@ -58,6 +59,7 @@ impl<'de, N: Network> Deserialize<'de> for ProverSolution<N> {
 mod tests {
     use super::*;
     use console::{account::PrivateKey, network::Testnet3};
+    use snarkvm_utilities::assert_serde_consistency;
 
     type CurrentNetwork = Testnet3;
 
@@ -104,4 +106,20 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_serde_consistency() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for random_v in [None, Some(rng.gen())] {
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let address = Address::try_from(private_key)?;
+
+            let partial_solution = PartialSolution::new(address, u64::rand(&mut rng), KZGCommitment(rng.gen()));
+            let prover_solution = ProverSolution::new(partial_solution, KZGProof { w: rng.gen(), random_v });
+            assert_serde_consistency!(ProverSolution<CurrentNetwork>, prover_solution);
+        }
+
+        Ok(())
+    }
 }
@@ -19,7 +19,7 @@ use super::*;
 impl<N: Network> FromBytes for PuzzleCommitment<N> {
     /// Reads the puzzle commitment from the buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let commitment = KZGCommitment::read_le(&mut reader)?;
+        let commitment = KZGCommitment::read_le(&mut reader).field_context("commitment")?;
 
         Ok(Self::new(commitment))
     }
@@ -36,6 +36,8 @@ impl<N: Network> ToBytes for PuzzleCommitment<N> {
 mod tests {
     use super::*;
     use console::network::Testnet3;
+    use snarkvm_curves::{bls12_377::G1Affine, AffineCurve};
+    use snarkvm_utilities::{FromHex, ToHex};
 
     type CurrentNetwork = Testnet3;
 
@@ -43,14 +45,31 @@ mod tests {
     fn test_bytes() -> Result<()> {
         let mut rng = TestRng::default();
         // Sample a new puzzle commitment.
-        let expected = PuzzleCommitment::<CurrentNetwork>::new(KZGCommitment(rng.gen()));
+        let commitment = KZGCommitment(rng.gen());
+        let expected = PuzzleCommitment::<CurrentNetwork>::new(commitment);
 
         // Check the byte representation.
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected_bytes.len(), 48);
+        assert_eq!(expected_bytes, commitment.to_bytes_le()?);
         assert_eq!(expected, PuzzleCommitment::read_le(&expected_bytes[..])?);
         assert!(PuzzleCommitment::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
 
         Ok(())
     }
+
+    #[test]
+    fn test_pinned_bytes() -> Result<()> {
+        // Construct a puzzle commitment from a fixed point, so the encoding is deterministic.
+        let expected = PuzzleCommitment::<CurrentNetwork>::new(KZGCommitment(G1Affine::prime_subgroup_generator()));
+
+        // Ensure the encoding matches the byte layout of the original implementation.
+        // The compressed commitment.
+        let expected_hex =
+            "66db6f1030f91f0f1824381daad6321c43739c828b3d8d69f75711a97a2d524c3d54201c61a85ca1b3835901b6a29400";
+        assert_eq!(expected.to_hex()?, expected_hex);
+        assert_eq!(expected, PuzzleCommitment::from_hex(expected_hex)?);
+
+        Ok(())
+    }
 }
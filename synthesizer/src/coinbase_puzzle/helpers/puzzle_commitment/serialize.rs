@@ -40,6 +40,7 @@ impl<'de, N: Network> Deserialize<'de> for PuzzleCommitment<N> {
 mod tests {
     use super::*;
     use console::network::Testnet3;
+    use snarkvm_utilities::assert_serde_consistency;
 
     type CurrentNetwork = Testnet3;
 
@@ -80,4 +81,14 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_serde_consistency() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            let puzzle_commitment = PuzzleCommitment::<CurrentNetwork>::new(KZGCommitment(rng.gen()));
+            assert_serde_consistency!(PuzzleCommitment<CurrentNetwork>, puzzle_commitment);
+        }
+    }
 }
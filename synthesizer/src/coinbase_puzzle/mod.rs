@@ -26,16 +26,17 @@ mod tests;
 use crate::UniversalSRS;
 use console::{
     account::Address,
-    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, Network, Result, ToBytes},
+    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, CryptoRng, FromBytes, Network, Result, Rng, ToBytes},
     program::cfg_into_iter,
 };
 use snarkvm_algorithms::{
-    fft::{DensePolynomial, EvaluationDomain},
+    fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
     msm::VariableBase,
     polycommit::kzg10::{KZGCommitment, UniversalParams as SRS, KZG10},
 };
-use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_parameters::testnet3::{CoinbaseProvingKeyBytes, CoinbaseVerifyingKeyBytes};
 
 use std::sync::Arc;
 
@@ -62,7 +63,33 @@ impl<N: Network> CoinbasePuzzle<N> {
     }
 
     /// Load the coinbase puzzle proving and verifying keys.
+    ///
+    /// The published keys are loaded with `CoinbaseProvingKeyBytes` and `CoinbaseVerifyingKeyBytes`,
+    /// which check their size and checksum, and are then checked for consistency with `from_parameter_bytes`.
+    /// If the published keys cannot be loaded, e.g. when offline, they are trimmed from the universal SRS instead.
     pub fn load() -> Result<Self> {
+        let degree = N::COINBASE_PUZZLE_DEGREE;
+        // Ensure the published keys were trimmed to the degree of the network.
+        if CoinbaseProvingKeyBytes::degree() != degree {
+            return Self::load_from_srs();
+        }
+        // Load the published keys.
+        let (proving_key_bytes, verifying_key_bytes) =
+            match (CoinbaseProvingKeyBytes::load_bytes(), CoinbaseVerifyingKeyBytes::load_bytes()) {
+                (Ok(proving_key_bytes), Ok(verifying_key_bytes)) => (proving_key_bytes, verifying_key_bytes),
+                _ => return Self::load_from_srs(),
+            };
+        let puzzle = Self::from_parameter_bytes(&proving_key_bytes, &verifying_key_bytes)?;
+        // Ensure the proving key supports the degree in the metadata.
+        ensure!(
+            puzzle.coinbase_proving_key()?.product_domain == Self::product_domain(degree)?,
+            "The published coinbase proving key does not support degree {degree}"
+        );
+        Ok(puzzle)
+    }
+
+    /// Load the coinbase puzzle proving and verifying keys, by trimming the universal SRS.
+    pub fn load_from_srs() -> Result<Self> {
         let max_degree = N::COINBASE_PUZZLE_DEGREE;
         // Load the universal SRS.
         let universal_srs = UniversalSRS::<N>::load()?;
@@ -70,6 +97,49 @@ impl<N: Network> CoinbasePuzzle<N> {
         Self::trim(&*universal_srs, PuzzleConfig { degree: max_degree })
     }
 
+    /// Initializes the coinbase puzzle prover from the bytes of a published proving key and verifying key.
+    ///
+    /// This method ensures the proving key embeds the given verifying key, that the verifying key has no hiding
+    /// generator, as coinbase proofs are never hiding, and that the Lagrange basis in the proving key sums to `G`
+    /// and, weighted by the product domain elements, to `beta * G`, which is checked with a pairing against the
+    /// verifying key's `beta_h`. These checks reject mismatched or corrupted pairs, but as only two combinations
+    /// of the basis are checked, they do not prove that every element of the basis was derived from `beta`.
+    /// Only the SRS can establish that, by recomputing the basis with `trim`.
+    ///
+    /// The published keys should be fetched with `CoinbaseProvingKeyBytes` and `CoinbaseVerifyingKeyBytes`
+    /// from `snarkvm-parameters`, which check the size and checksum of the bytes, as `CoinbasePuzzle::load` does.
+    pub fn from_parameter_bytes(proving_key_bytes: &[u8], verifying_key_bytes: &[u8]) -> Result<Self> {
+        let pk = CoinbaseProvingKey::<N>::from_bytes_le(proving_key_bytes)?;
+        let vk = CoinbaseVerifyingKey::<N>::from_bytes_le(verifying_key_bytes)?;
+
+        // Ensure the proving key was published alongside the given verifying key.
+        ensure!(pk.verifying_key == vk, "The coinbase proving key does not match the coinbase verifying key");
+        ensure!(
+            vk.prepared_h == vk.h.prepare() && vk.prepared_beta_h == vk.beta_h.prepare(),
+            "The coinbase verifying key contains inconsistent prepared elements"
+        );
+        // Coinbase proofs are never hiding, so the verifying key must not support hiding proofs.
+        ensure!(vk.gamma_g.is_zero(), "The coinbase verifying key must not have a hiding generator");
+
+        // The Lagrange polynomials over the product domain satisfy `\sum_i L_i(X) = 1` and `\sum_i w^i L_i(X) = X`.
+        // Hence, the Lagrange basis must sum to `G`, and its combination with the domain elements must equal `beta * G`.
+        let lagrange_basis = &pk.lagrange_basis_at_beta_g;
+        let g = lagrange_basis
+            .iter()
+            .map(|base| base.to_projective())
+            .sum::<<N::PairingCurve as PairingEngine>::G1Projective>();
+        ensure!(g.to_affine() == vk.g, "The coinbase Lagrange basis does not match the verifying key");
+        let scalars = cfg_iter!(pk.product_domain_elements).map(|element| element.to_bigint()).collect::<Vec<_>>();
+        let beta_g = VariableBase::msm(lagrange_basis, &scalars).to_affine();
+        let is_consistent = <N::PairingCurve as PairingEngine>::product_of_pairings(
+            [(&beta_g.prepare(), &vk.prepared_h), (&(-vk.g).prepare(), &vk.prepared_beta_h)].iter().copied(),
+        )
+        .is_one();
+        ensure!(is_consistent, "The coinbase Lagrange basis was not derived from the verifying key's beta");
+
+        Ok(Self::Prover(Arc::new(pk)))
+    }
+
     pub fn trim(srs: &SRS<N::PairingCurve>, config: PuzzleConfig) -> Result<Self> {
         // As above, we must support committing to the product of two degree `n` polynomials.
         // Thus, the SRS must support committing to a polynomial of degree `2n - 1`.
@@ -79,7 +149,7 @@ impl<N: Network> CoinbasePuzzle<N> {
         // Hence, we request the powers of beta for the interval [0, 2n].
         let product_domain = Self::product_domain(config.degree)?;
 
-        let lagrange_basis_at_beta_g = srs.lagrange_basis(product_domain)?;
+        let lagrange_basis_at_beta_g = srs.shared_lagrange_basis(product_domain)?;
         let fft_precomputation = product_domain.precompute_fft();
         let product_domain_elements = product_domain.elements().collect();
 
@@ -116,19 +186,22 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Prover(coinbase_proving_key) => coinbase_proving_key,
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
+        ensure!(
+            epoch_challenge.product_domain() == pk.product_domain,
+            "The epoch challenge degree does not match the coinbase proving key"
+        );
 
-        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
-
-        let product_evaluations = {
-            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
-            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
-                &polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            );
-            product_evaluations
-        };
-        let (commitment, _rand) =
-            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+        // The prover polynomial is only needed in evaluation form, so hash directly to its evaluations.
+        let polynomial_evaluations =
+            Self::prover_polynomial_evaluations(epoch_challenge, address, nonce, pk.product_domain)?;
+        let product_evaluations = &polynomial_evaluations * epoch_challenge.epoch_polynomial_evaluations();
+        let (commitment, _rand) = KZG10::commit_lagrange(
+            &pk.lagrange_basis(),
+            &product_evaluations.evaluations,
+            None,
+            &Default::default(),
+            None,
+        )?;
 
         let partial_solution = PartialSolution::new(address, nonce, commitment);
 
@@ -142,22 +215,56 @@ impl<N: Network> CoinbasePuzzle<N> {
         }
 
         let point = hash_commitment(&commitment)?;
-        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
+        let product_eval_at_point = product_evaluations.evaluate(&point);
 
         let proof = KZG10::open_lagrange(
             &pk.lagrange_basis(),
             pk.product_domain_elements(),
-            &product_evaluations,
+            &product_evaluations.evaluations,
             point,
             product_eval_at_point,
         )?;
-        ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
+        KZG10::check_hiding_is_supported(pk.verifying_key.gamma_g.is_zero(), [&proof])?;
 
         debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
 
         Ok(ProverSolution::new(partial_solution, proof))
     }
 
+    /// Returns a coinbase solution for the given epoch challenge and prover solutions,
+    /// after checking that each prover solution is valid and meets the proof target.
+    pub fn accumulate(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_solutions: &[ProverSolution<N>],
+        proof_target: u64,
+    ) -> Result<CoinbaseSolution<N>> {
+        // Prepare the coinbase verifying key once, for all of the prover solutions.
+        let prepared_verifying_key = CoinbasePreparedVerifyingKey::<N>::from(self.coinbase_verifying_key());
+
+        // Compute the challenge point and the claimed value for each of the prover solutions.
+        let (points, values): (Vec<_>, Vec<_>) = cfg_iter!(prover_solutions)
+            .map(|solution| solution.to_challenge_point_and_value(epoch_challenge, proof_target))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let commitments = prover_solutions.iter().map(|solution| *solution.commitment()).collect::<Vec<_>>();
+        let proofs = prover_solutions.iter().map(|solution| *solution.proof()).collect::<Vec<_>>();
+
+        // Ensure the prover solutions are valid, by checking their KZG proofs in a single batch.
+        let is_valid = KZG10::batch_check_prepared(
+            &prepared_verifying_key,
+            &commitments,
+            &points,
+            &values,
+            &proofs,
+            &mut rand::thread_rng(),
+        )?;
+        ensure!(is_valid, "Cannot accumulate an invalid prover solution");
+
+        self.accumulate_unchecked(epoch_challenge, prover_solutions)
+    }
+
     /// Returns a coinbase solution for the given epoch challenge and prover solutions.
     ///
     /// # Note
@@ -186,10 +293,15 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Prover(coinbase_proving_key) => coinbase_proving_key,
             Self::Verifier(_) => bail!("Cannot accumulate the coinbase puzzle with a verifier"),
         };
+        ensure!(
+            epoch_challenge.product_domain() == pk.product_domain,
+            "The epoch challenge degree does not match the coinbase proving key"
+        );
         ensure!(!has_duplicates(prover_solutions), "Cannot accumulate duplicate prover solutions");
 
         let (prover_polynomials, partial_solutions): (Vec<_>, Vec<_>) = cfg_iter!(prover_solutions)
             .filter_map(|solution| {
+                // Coinbase proofs are never hiding, so skip any prover solution with a hiding proof.
                 if solution.proof().is_hiding() {
                     return None;
                 }
@@ -209,11 +321,12 @@ impl<N: Network> CoinbasePuzzle<N> {
         };
 
         // Construct the provers polynomial.
+        // Each partial sum is normalized by `add_assign_scaled`, and the partial sums are combined with `Add`,
+        // so the accumulated polynomial has no leading zero coefficients however the fold is split.
         let accumulated_prover_polynomial = cfg_into_iter!(prover_polynomials)
             .zip_eq(challenges)
-            .fold(DensePolynomial::zero, |mut accumulator, (mut prover_polynomial, challenge)| {
-                prover_polynomial *= challenge;
-                accumulator += &prover_polynomial;
+            .fold(DensePolynomial::zero, |mut accumulator, (prover_polynomial, challenge)| {
+                accumulator.add_assign_scaled(&prover_polynomial, challenge);
                 accumulator
             })
             .sum::<DensePolynomial<_>>();
@@ -240,9 +353,7 @@ impl<N: Network> CoinbasePuzzle<N> {
         )?;
 
         // Ensure the coinbase proof is non-hiding.
-        if proof.is_hiding() {
-            bail!("The coinbase proof must be non-hiding");
-        }
+        KZG10::check_hiding_is_supported(pk.verifying_key.gamma_g.is_zero(), [&proof])?;
 
         // Return the accumulated proof.
         Ok(CoinbaseSolution::new(partial_solutions, proof))
@@ -256,6 +367,81 @@ impl<N: Network> CoinbasePuzzle<N> {
         coinbase_target: u64,
         proof_target: u64,
     ) -> Result<bool> {
+        // Compute the accumulator commitment, challenge point, and evaluation.
+        let (accumulator_commitment, accumulator_point, accumulator_evaluation) =
+            Self::to_accumulator_instance(coinbase_solution, epoch_challenge, coinbase_target, proof_target)?;
+
+        // Return the verification result.
+        Ok(KZG10::check(
+            self.coinbase_verifying_key(),
+            &accumulator_commitment,
+            accumulator_point,
+            accumulator_evaluation,
+            coinbase_solution.proof(),
+        )?)
+    }
+
+    /// Returns `true` if all of the given coinbase solutions are valid.
+    ///
+    /// The KZG proofs of the coinbase solutions are checked together,
+    /// using two pairings for the entire batch.
+    pub fn verify_batch<R: Rng + CryptoRng>(
+        &self,
+        coinbase_solutions: &[CoinbaseSolution<N>],
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+        rng: &mut R,
+    ) -> Result<bool> {
+        // Prepare the coinbase verifying key once, for all of the coinbase solutions.
+        let prepared_verifying_key = CoinbasePreparedVerifyingKey::<N>::from(self.coinbase_verifying_key());
+
+        // Compute the accumulator instance for each coinbase solution.
+        let instances = cfg_iter!(coinbase_solutions)
+            .map(|coinbase_solution| {
+                Self::to_accumulator_instance(coinbase_solution, epoch_challenge, coinbase_target, proof_target)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut commitments = Vec::with_capacity(instances.len());
+        let mut points = Vec::with_capacity(instances.len());
+        let mut evaluations = Vec::with_capacity(instances.len());
+        for (commitment, point, evaluation) in instances {
+            commitments.push(commitment);
+            points.push(point);
+            evaluations.push(evaluation);
+        }
+        let proofs = coinbase_solutions.iter().map(|coinbase_solution| *coinbase_solution.proof()).collect::<Vec<_>>();
+
+        // Return the verification result.
+        Ok(KZG10::batch_check_prepared(&prepared_verifying_key, &commitments, &points, &evaluations, &proofs, rng)?)
+    }
+
+    /// Returns the coinbase proving key.
+    pub fn coinbase_proving_key(&self) -> Result<&CoinbaseProvingKey<N>> {
+        match self {
+            Self::Prover(coinbase_proving_key) => Ok(coinbase_proving_key),
+            Self::Verifier(_) => bail!("Cannot fetch the coinbase proving key with a verifier"),
+        }
+    }
+
+    /// Returns the coinbase verifying key.
+    pub fn coinbase_verifying_key(&self) -> &CoinbaseVerifyingKey<N> {
+        match self {
+            Self::Prover(coinbase_proving_key) => &coinbase_proving_key.verifying_key,
+            Self::Verifier(coinbase_verifying_key) => coinbase_verifying_key,
+        }
+    }
+}
+
+impl<N: Network> CoinbasePuzzle<N> {
+    /// Returns the accumulator commitment, the accumulator challenge point, and the claimed
+    /// evaluation at that point, for the given coinbase solution.
+    fn to_accumulator_instance(
+        coinbase_solution: &CoinbaseSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        coinbase_target: u64,
+        proof_target: u64,
+    ) -> Result<(KZGCommitment<N::PairingCurve>, N::Field, N::Field)> {
         // Ensure the coinbase solution is not empty.
         if coinbase_solution.is_empty() {
             bail!("The coinbase solution does not contain any partial solutions");
@@ -270,11 +456,6 @@ impl<N: Network> CoinbasePuzzle<N> {
             );
         }
 
-        // Ensure the coinbase proof is non-hiding.
-        if coinbase_solution.proof().is_hiding() {
-            bail!("The coinbase proof must be non-hiding");
-        }
-
         // Ensure the coinbase proof meets the required coinbase target.
         if coinbase_solution.to_cumulative_proof_target()? < coinbase_target as u128 {
             bail!("The coinbase proof does not meet the coinbase target");
@@ -285,6 +466,10 @@ impl<N: Network> CoinbasePuzzle<N> {
             bail!("The coinbase solution contains duplicate puzzle commitments");
         }
 
+        // Ensure the commitments are in the correct subgroup, checking them in a single batch.
+        let commitments = coinbase_solution.partial_solutions().iter().map(|solution| *solution.commitment());
+        KZGCommitment::batch_validate(&commitments.collect::<Vec<_>>())?;
+
         // Compute the prover polynomials.
         let prover_polynomials = cfg_iter!(coinbase_solution.partial_solutions())
             // Ensure that each of the prover solutions meets the required proof target.
@@ -325,54 +510,21 @@ impl<N: Network> CoinbasePuzzle<N> {
         let accumulator_commitment =
             KZGCommitment::<N::PairingCurve>(VariableBase::msm(&commitments, &fs_challenges).into());
 
-        // Retrieve the coinbase verifying key.
-        let coinbase_verifying_key = match self {
-            Self::Prover(coinbase_proving_key) => &coinbase_proving_key.verifying_key,
-            Self::Verifier(coinbase_verifying_key) => coinbase_verifying_key,
-        };
-
-        // Return the verification result.
-        Ok(KZG10::check(
-            coinbase_verifying_key,
-            &accumulator_commitment,
-            accumulator_point,
-            accumulator_evaluation,
-            coinbase_solution.proof(),
-        )?)
-    }
-
-    /// Returns the coinbase proving key.
-    pub fn coinbase_proving_key(&self) -> Result<&CoinbaseProvingKey<N>> {
-        match self {
-            Self::Prover(coinbase_proving_key) => Ok(coinbase_proving_key),
-            Self::Verifier(_) => bail!("Cannot fetch the coinbase proving key with a verifier"),
-        }
+        Ok((accumulator_commitment, accumulator_point, accumulator_evaluation))
     }
 
-    /// Returns the coinbase verifying key.
-    pub fn coinbase_verifying_key(&self) -> &CoinbaseVerifyingKey<N> {
-        match self {
-            Self::Prover(coinbase_proving_key) => &coinbase_proving_key.verifying_key,
-            Self::Verifier(coinbase_verifying_key) => coinbase_verifying_key,
-        }
-    }
-}
-
-impl<N: Network> CoinbasePuzzle<N> {
     /// Checks that the degree for the epoch and prover polynomial is within bounds,
     /// and returns the evaluation domain for the product polynomial.
-    pub(crate) fn product_domain(degree: u32) -> Result<EvaluationDomain<N::Field>> {
+    ///
+    /// The epoch and prover polynomials both have degree `degree`, so their product has degree `2 * degree`,
+    /// and `2 * degree + 1` coefficients, counting the constant term. The product domain is the smallest
+    /// power-of-two domain with at least that many elements. The proving key, its Lagrange basis,
+    /// and the epoch polynomial evaluations are all over this domain.
+    pub fn product_domain(degree: u32) -> Result<EvaluationDomain<N::Field>> {
         ensure!(degree != 0, "Degree cannot be zero");
-        let num_coefficients = degree.checked_add(1).ok_or_else(|| anyhow!("Degree is too large"))?;
-        let product_num_coefficients = num_coefficients
-            .checked_mul(2)
-            .and_then(|t| t.checked_sub(1))
-            .ok_or_else(|| anyhow!("Degree is too large"))?;
-        assert_eq!(product_num_coefficients, 2 * degree + 1);
-        let product_domain =
-            EvaluationDomain::new(product_num_coefficients.try_into()?).ok_or_else(|| anyhow!("Invalid degree"))?;
-        assert_eq!(product_domain.size(), (product_num_coefficients as usize).checked_next_power_of_two().unwrap());
-        Ok(product_domain)
+        let product_num_coefficients =
+            degree.checked_mul(2).and_then(|d| d.checked_add(1)).ok_or_else(|| anyhow!("Degree is too large"))?;
+        Ok(EvaluationDomain::try_new(product_num_coefficients.try_into()?)?)
     }
 
     /// Returns the prover polynomial for the coinbase puzzle.
@@ -381,14 +533,36 @@ impl<N: Network> CoinbasePuzzle<N> {
         address: Address<N>,
         nonce: u64,
     ) -> Result<DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>> {
-        let input = {
-            let mut bytes = [0u8; 76];
-            bytes[..4].copy_from_slice(&epoch_challenge.epoch_number().to_bytes_le()?);
-            bytes[4..36].copy_from_slice(&epoch_challenge.epoch_block_hash().to_bytes_le()?);
-            bytes[36..68].copy_from_slice(&address.to_bytes_le()?);
-            bytes[68..].copy_from_slice(&nonce.to_le_bytes());
-            bytes
-        };
+        let input = Self::prover_polynomial_input(epoch_challenge, address, nonce)?;
         Ok(hash_to_polynomial::<<N::PairingCurve as PairingEngine>::Fr>(&input, epoch_challenge.degree()))
     }
+
+    /// Returns the evaluations of the prover polynomial for the coinbase puzzle over the given domain.
+    fn prover_polynomial_evaluations(
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+        domain: EvaluationDomain<N::Field>,
+    ) -> Result<EvaluationsOnDomain<<N::PairingCurve as PairingEngine>::Fr>> {
+        let input = Self::prover_polynomial_input(epoch_challenge, address, nonce)?;
+        hash_to_polynomial_evaluations::<<N::PairingCurve as PairingEngine>::Fr>(
+            &input,
+            epoch_challenge.degree(),
+            domain,
+        )
+    }
+
+    /// Returns the input for the prover polynomial, as `( epoch_number || epoch_block_hash || address || nonce )`.
+    fn prover_polynomial_input(
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+    ) -> Result<[u8; 76]> {
+        let mut bytes = [0u8; 76];
+        bytes[..4].copy_from_slice(&epoch_challenge.epoch_number().to_bytes_le()?);
+        bytes[4..36].copy_from_slice(&epoch_challenge.epoch_block_hash().to_bytes_le()?);
+        bytes[36..68].copy_from_slice(&address.to_bytes_le()?);
+        bytes[68..].copy_from_slice(&nonce.to_le_bytes());
+        Ok(bytes)
+    }
 }
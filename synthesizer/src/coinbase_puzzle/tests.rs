@@ -16,6 +16,8 @@
 
 use super::*;
 use console::{account::*, network::Testnet3};
+use snarkvm_algorithms::polycommit::{kzg10::KZGProof, PCError};
+use snarkvm_curves::bls12_377::{Fq, G1Affine};
 use snarkvm_utilities::Uniform;
 
 use rand::RngCore;
@@ -47,6 +49,7 @@ fn test_coinbase_puzzle() {
                 .collect::<Vec<_>>();
             let full_solution = puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap();
             assert!(puzzle.verify(&full_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+            assert_eq!(full_solution, puzzle.accumulate(&epoch_challenge, &solutions, 0u64).unwrap());
 
             let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
             assert!(!puzzle.verify(&full_solution, &bad_epoch_challenge, 0u64, 0u64).unwrap());
@@ -85,6 +88,144 @@ fn test_prover_solution_minimum_target() {
     }
 }
 
+#[test]
+fn test_coinbase_puzzle_verify_batch() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 9) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Construct several coinbase solutions, each with its own accumulator point.
+    let coinbase_solutions = (1..6)
+        .map(|batch_size| {
+            let solutions = (0..batch_size)
+                .map(|_| {
+                    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+                    let address = Address::try_from(private_key).unwrap();
+                    let nonce = u64::rand(&mut rng);
+                    puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+                })
+                .collect::<Vec<_>>();
+            puzzle.accumulate_unchecked(&epoch_challenge, &solutions).unwrap()
+        })
+        .collect::<Vec<_>>();
+    assert!(puzzle.verify_batch(&coinbase_solutions, &epoch_challenge, 0u64, 0u64, &mut rng).unwrap());
+
+    // Swap the proofs of two coinbase solutions, and ensure the batch is rejected.
+    let mut corrupted_solutions = coinbase_solutions.clone();
+    corrupted_solutions[0] =
+        CoinbaseSolution::new(coinbase_solutions[0].partial_solutions().to_vec(), *coinbase_solutions[1].proof());
+    assert!(!puzzle.verify(&corrupted_solutions[0], &epoch_challenge, 0u64, 0u64).unwrap());
+    assert!(!puzzle.verify_batch(&corrupted_solutions, &epoch_challenge, 0u64, 0u64, &mut rng).unwrap());
+
+    // Ensure the batch is rejected for a different epoch challenge.
+    let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    assert!(!puzzle.verify_batch(&coinbase_solutions, &bad_epoch_challenge, 0u64, 0u64, &mut rng).unwrap());
+}
+
+#[test]
+fn test_prover_solution_verify_prepared() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 9) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    // Prepare the verifying key once.
+    let verifying_key = puzzle.coinbase_verifying_key();
+    let prepared_verifying_key = CoinbasePreparedVerifyingKey::<Testnet3>::from(verifying_key);
+
+    for _ in 0..10 {
+        let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+        let address = Address::try_from(private_key).unwrap();
+        let nonce = u64::rand(&mut rng);
+
+        let solution = puzzle.prove(&epoch_challenge, address, nonce, None).unwrap();
+        assert!(solution.verify(verifying_key, &epoch_challenge, 0u64).unwrap());
+        assert!(solution.verify_prepared(&prepared_verifying_key, &epoch_challenge, 0u64).unwrap());
+        assert!(!solution.verify_prepared(&prepared_verifying_key, &bad_epoch_challenge, 0u64).unwrap());
+    }
+}
+
+#[test]
+fn test_hiding_proofs_are_rejected() {
+    let mut rng = TestRng::default();
+
+    let degree = (1 << 9) - 1;
+    let srs = CoinbasePuzzle::<Testnet3>::setup(PuzzleConfig { degree }).unwrap();
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let verifying_key = puzzle.coinbase_verifying_key();
+    let prepared_verifying_key = CoinbasePreparedVerifyingKey::<Testnet3>::from(verifying_key);
+    assert!(verifying_key.gamma_g.is_zero());
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let solution = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+    assert!(!solution.proof().is_hiding());
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[solution]).unwrap();
+
+    // Ensure a prover solution with a hiding proof is rejected, as the coinbase verifying key is non-hiding.
+    let partial_solution = PartialSolution::new(solution.address(), solution.nonce(), solution.commitment());
+    let hiding_proof = KZGProof { w: solution.proof().w, random_v: Some(rng.gen()) };
+    let hiding_solution = ProverSolution::new(partial_solution, hiding_proof);
+    // The hiding proof is an error, rather than a solution that fails to verify.
+    let is_hiding_error =
+        |error: anyhow::Error| matches!(error.downcast_ref::<PCError>(), Some(PCError::HidingProofForNonHidingKey));
+    assert!(is_hiding_error(hiding_solution.verify(verifying_key, &epoch_challenge, 0u64).unwrap_err()));
+    assert!(is_hiding_error(
+        hiding_solution.verify_prepared(&prepared_verifying_key, &epoch_challenge, 0u64).unwrap_err()
+    ));
+    assert!(puzzle.accumulate(&epoch_challenge, &[hiding_solution], 0u64).is_err());
+
+    // Ensure a coinbase solution with a hiding proof is rejected.
+    let hiding_proof = KZGProof { w: coinbase_solution.proof().w, random_v: Some(rng.gen()) };
+    let hiding_coinbase_solution = CoinbaseSolution::new(coinbase_solution.partial_solutions().to_vec(), hiding_proof);
+    assert!(puzzle.verify(&hiding_coinbase_solution, &epoch_challenge, 0u64, 0u64).is_err());
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+}
+
+#[test]
+fn test_invalid_commitments_are_rejected() {
+    let mut rng = TestRng::default();
+
+    let degree = (1 << 9) - 1;
+    let srs = CoinbasePuzzle::<Testnet3>::setup(PuzzleConfig { degree }).unwrap();
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let solution = puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+    let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[solution]).unwrap();
+    assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
+
+    // Sample a point on the curve that is not in the prime-order subgroup.
+    let invalid_point = loop {
+        if let Some(point) = G1Affine::from_x_coordinate(Fq::rand(&mut rng), true) {
+            if !point.is_in_correct_subgroup_assuming_on_curve() {
+                break point;
+            }
+        }
+    };
+
+    // Ensure a coinbase solution with a commitment outside of the subgroup is rejected.
+    let partial_solution = PartialSolution::new(solution.address(), solution.nonce(), KZGCommitment(invalid_point));
+    let invalid_coinbase_solution = CoinbaseSolution::new(vec![partial_solution], *coinbase_solution.proof());
+    let error = puzzle.verify(&invalid_coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap_err();
+    assert_eq!(error.to_string(), "Commitment 0 is not in the correct subgroup");
+}
+
 #[test]
 fn test_edge_case_for_degree() {
     let mut rng = rand::thread_rng();
@@ -108,3 +249,194 @@ fn test_edge_case_for_degree() {
     let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[prover_solution]).unwrap();
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
 }
+
+#[test]
+fn test_product_domain() {
+    // The product of two degree-`d` polynomials has `2d + 1` coefficients, rounded up to a power of two.
+    for (degree, expected_size) in [(1, 4), (2, 8), (3, 8), (4, 16), ((1 << 13) - 1, 1 << 14), (1 << 13, 1 << 15)] {
+        let domain = CoinbasePuzzle::<Testnet3>::product_domain(degree).unwrap();
+        assert_eq!(domain.size(), expected_size, "Unexpected product domain size for degree {degree}");
+    }
+    assert!(CoinbasePuzzle::<Testnet3>::product_domain(0).is_err());
+    assert!(CoinbasePuzzle::<Testnet3>::product_domain(u32::MAX).is_err());
+
+    // The proving key, its Lagrange basis, and the epoch challenge agree on the product domain.
+    let srs = CoinbasePuzzle::<Testnet3>::setup(PuzzleConfig { degree: 1 << 10 }).unwrap();
+    for degree in [(1 << 8) - 1, 1 << 8] {
+        let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+        let pk = puzzle.coinbase_proving_key().unwrap();
+        let epoch_challenge = EpochChallenge::<Testnet3>::new(0, Default::default(), degree).unwrap();
+        assert_eq!(pk.product_domain, CoinbasePuzzle::<Testnet3>::product_domain(degree).unwrap());
+        assert_eq!(pk.lagrange_basis_at_beta_g.len(), pk.product_domain.size());
+        assert_eq!(epoch_challenge.product_domain(), pk.product_domain);
+
+        // A mismatched epoch challenge is rejected.
+        let mismatched = EpochChallenge::<Testnet3>::new(0, Default::default(), 2 * degree).unwrap();
+        let address = Address::try_from(PrivateKey::<Testnet3>::new(&mut TestRng::default()).unwrap()).unwrap();
+        assert!(puzzle.prove(&mismatched, address, 0, None).is_err());
+    }
+}
+
+#[test]
+fn test_trim_lagrange_basis() {
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    // Trimming repeatedly, and for different degrees, yields the Lagrange basis over the product domain.
+    for degree in [(1 << 9) - 1, (1 << 9) - 1, (1 << 10) - 1, (1 << 9) - 1] {
+        let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+        let pk = puzzle.coinbase_proving_key().unwrap();
+        assert_eq!(*pk.lagrange_basis_at_beta_g, srs.lagrange_basis(pk.product_domain).unwrap());
+    }
+}
+
+#[test]
+fn test_from_parameter_bytes() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 9) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let pk = puzzle.coinbase_proving_key().unwrap();
+    let pk_bytes = pk.to_bytes_le().unwrap();
+    let vk_bytes = puzzle.coinbase_verifying_key().to_bytes_le().unwrap();
+
+    // Ensure the loaded puzzle produces solutions that verify against the original puzzle.
+    let loaded_puzzle = CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&pk_bytes, &vk_bytes).unwrap();
+    let loaded_pk = loaded_puzzle.coinbase_proving_key().unwrap();
+    assert_eq!(loaded_pk.lagrange_basis_at_beta_g, pk.lagrange_basis_at_beta_g);
+    assert_eq!(loaded_pk.product_domain, pk.product_domain);
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let solution = loaded_puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap();
+    assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0u64).unwrap());
+
+    // Ensure truncated keys are rejected.
+    assert!(CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&pk_bytes[..pk_bytes.len() - 1], &vk_bytes).is_err());
+    assert!(CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&pk_bytes[..pk_bytes.len() / 2], &vk_bytes).is_err());
+    assert!(CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&pk_bytes, &vk_bytes[..vk_bytes.len() - 1]).is_err());
+
+    // Ensure a proving key for a different degree is rejected.
+    let other_puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree: (1 << 10) - 1 }).unwrap();
+    let other_pk = other_puzzle.coinbase_proving_key().unwrap();
+    let mut mismatched_pk = other_pk.clone();
+    mismatched_pk.product_domain = pk.product_domain;
+    let mismatched_pk_bytes = mismatched_pk.to_bytes_le().unwrap();
+    assert!(CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&mismatched_pk_bytes, &vk_bytes).is_err());
+
+    // Ensure a verifying key with a different beta is rejected.
+    let mut mismatched_vk = puzzle.coinbase_verifying_key().clone();
+    mismatched_vk.beta_h = mismatched_vk.h;
+    mismatched_vk.prepared_beta_h = mismatched_vk.prepared_h.clone();
+    let mismatched_vk_bytes = mismatched_vk.to_bytes_le().unwrap();
+    assert!(CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&pk_bytes, &mismatched_vk_bytes).is_err());
+
+    // Ensure the check does not rely on the verifying key embedded in the proving key.
+    let mut mismatched_pk = pk.clone();
+    mismatched_pk.verifying_key = mismatched_vk;
+    let mismatched_pk_bytes = mismatched_pk.to_bytes_le().unwrap();
+    assert!(CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&mismatched_pk_bytes, &mismatched_vk_bytes).is_err());
+
+    // Ensure a verifying key with a hiding generator is rejected, even when it is otherwise consistent.
+    let mut hiding_vk = puzzle.coinbase_verifying_key().clone();
+    hiding_vk.gamma_g = hiding_vk.g;
+    let hiding_vk_bytes = hiding_vk.to_bytes_le().unwrap();
+    let mut hiding_pk = pk.clone();
+    hiding_pk.verifying_key = hiding_vk;
+    let hiding_pk_bytes = hiding_pk.to_bytes_le().unwrap();
+    let error = CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&hiding_pk_bytes, &hiding_vk_bytes).unwrap_err();
+    assert_eq!(error.to_string(), "The coinbase verifying key must not have a hiding generator");
+}
+
+#[test]
+fn test_published_coinbase_keys() {
+    let pk_bytes = CoinbaseProvingKeyBytes::load_bytes().unwrap();
+    let vk_bytes = CoinbaseVerifyingKeyBytes::load_bytes().unwrap();
+
+    // Ensure the published keys are consistent, and support the degree of the network.
+    let degree = <Testnet3 as Network>::COINBASE_PUZZLE_DEGREE;
+    assert_eq!(CoinbaseProvingKeyBytes::degree(), degree);
+    let puzzle = CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&pk_bytes, &vk_bytes).unwrap();
+    let product_domain = CoinbasePuzzle::<Testnet3>::product_domain(degree).unwrap();
+    assert_eq!(puzzle.coinbase_proving_key().unwrap().product_domain, product_domain);
+
+    // Ensure truncated keys are rejected by the loaders, and when parsed.
+    let truncated_pk_bytes = &pk_bytes[..pk_bytes.len() - 1];
+    let truncated_vk_bytes = &vk_bytes[..vk_bytes.len() - 1];
+    assert!(CoinbaseProvingKeyBytes::verify_bytes(truncated_pk_bytes).is_err());
+    assert!(CoinbaseVerifyingKeyBytes::verify_bytes(truncated_vk_bytes).is_err());
+    assert!(CoinbasePuzzle::<Testnet3>::from_parameter_bytes(truncated_pk_bytes, &vk_bytes).is_err());
+    assert!(CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&pk_bytes, truncated_vk_bytes).is_err());
+
+    // Ensure each loader rejects the bytes of the other key.
+    assert!(CoinbaseProvingKeyBytes::verify_bytes(&vk_bytes).is_err());
+    assert!(CoinbaseVerifyingKeyBytes::verify_bytes(&pk_bytes).is_err());
+
+    // Ensure a verifying key that does not match the published proving key is rejected.
+    let mut mismatched_vk = CoinbaseVerifyingKey::<Testnet3>::read_le(&vk_bytes[..]).unwrap();
+    mismatched_vk.beta_h = mismatched_vk.h;
+    mismatched_vk.prepared_beta_h = mismatched_vk.prepared_h.clone();
+    let mismatched_vk_bytes = mismatched_vk.to_bytes_le().unwrap();
+    assert!(CoinbaseVerifyingKeyBytes::verify_bytes(&mismatched_vk_bytes).is_err());
+    assert!(CoinbasePuzzle::<Testnet3>::from_parameter_bytes(&pk_bytes, &mismatched_vk_bytes).is_err());
+}
+
+#[test]
+fn test_proving_key_lagrange_basis_length() {
+    let degree = (1 << 9) - 1;
+    let product_domain = CoinbasePuzzle::<Testnet3>::product_domain(degree).unwrap();
+    let size = product_domain.size() as u64;
+
+    // Ensure a Lagrange basis length that does not match the product domain is rejected before any point is read.
+    // The blob ends right after the length, so reading a point would fail with a different error.
+    for length in [0, size - 1, size + 1, u64::MAX] {
+        let mut bytes = vec![];
+        0u16.write_le(&mut bytes).unwrap();
+        (size as u32).write_le(&mut bytes).unwrap();
+        length.write_le(&mut bytes).unwrap();
+        let error = CoinbaseProvingKey::<Testnet3>::read_le(&bytes[..]).unwrap_err();
+        assert_eq!(error.to_string(), "The coinbase Lagrange basis does not match the product domain");
+    }
+
+    // Ensure a matching length proceeds to read the points.
+    let mut bytes = vec![];
+    0u16.write_le(&mut bytes).unwrap();
+    (size as u32).write_le(&mut bytes).unwrap();
+    size.write_le(&mut bytes).unwrap();
+    let error = CoinbaseProvingKey::<Testnet3>::read_le(&bytes[..]).unwrap_err();
+    assert_eq!(error.to_string(), "could not deserialize the coinbase Lagrange basis");
+}
+
+#[test]
+fn test_hash_to_polynomial_evaluations() {
+    type Fr = <<Testnet3 as Environment>::PairingCurve as PairingEngine>::Fr;
+
+    let mut rng = TestRng::default();
+
+    for log_degree in 0..12 {
+        let degree = (1 << log_degree) - 1;
+        let mut input = [0u8; 76];
+        rng.fill_bytes(&mut input);
+        let polynomial = hash_to_polynomial::<Fr>(&input, degree);
+
+        for domain_size in [degree + 1, 2 * degree + 1, 4 * degree + 4] {
+            let domain = EvaluationDomain::new(domain_size as usize).unwrap();
+            let evaluations = hash_to_polynomial_evaluations::<Fr>(&input, degree, domain).unwrap();
+            // The evaluations interpolate to the hashed polynomial.
+            assert_eq!(evaluations.domain(), domain);
+            assert_eq!(evaluations.evaluations, polynomial.evaluate_over_domain_by_ref(domain).evaluations);
+            assert_eq!(evaluations.interpolate(), polynomial);
+        }
+
+        // The domain must be large enough to hold all of the coefficients.
+        if degree > 0 {
+            let domain = EvaluationDomain::new((degree as usize + 1) / 2).unwrap();
+            assert!(hash_to_polynomial_evaluations::<Fr>(&input, degree, domain).is_err());
+        }
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Field, Fields, GenericArgument, Index, Meta, NestedMeta, PathArguments, Type};
+
+/// The encoding of a single struct field.
+enum FieldKind {
+    /// The field is written with its own `ToBytes` implementation.
+    Plain,
+    /// The field is a `Vec`, written as a `u32` length prefix followed by its elements.
+    Vec,
+    /// The field is not written, and is restored with `Default::default()`.
+    Skip,
+}
+
+impl FieldKind {
+    /// Returns the encoding of the given field, based on its type and `#[bytes(...)]` attributes.
+    fn of(field: &Field) -> Result<Self, Error> {
+        let mut kind = if is_vec(&field.ty) { Self::Vec } else { Self::Plain };
+
+        for attribute in field.attrs.iter().filter(|attribute| attribute.path.is_ident("bytes")) {
+            let list = match attribute.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(Error::new_spanned(meta, "expected `#[bytes(skip)]`")),
+            };
+            for nested in list.nested.iter() {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => kind = Self::Skip,
+                    nested => return Err(Error::new_spanned(nested, "unsupported `bytes` attribute, expected `skip`")),
+                }
+            }
+        }
+        Ok(kind)
+    }
+}
+
+/// Returns `true` if the given type is a `Vec<T>`.
+fn is_vec(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => match path.path.segments.last() {
+            Some(segment) if segment.ident == "Vec" => match &segment.arguments {
+                PathArguments::AngleBracketed(arguments) => {
+                    arguments.args.len() == 1 && matches!(arguments.args.first(), Some(GenericArgument::Type(_)))
+                }
+                _ => false,
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the fields of the given struct, or an error if the input is not a struct.
+fn struct_fields<'a>(ast: &'a DeriveInput, trait_name: &str) -> Result<&'a Fields, Error> {
+    match &ast.data {
+        Data::Struct(data_struct) => Ok(&data_struct.fields),
+        _ => Err(Error::new_spanned(&ast.ident, format!("`{trait_name}` can only be derived for structs"))),
+    }
+}
+
+pub(super) fn impl_to_bytes(ast: &DeriveInput) -> TokenStream {
+    let fields = match struct_fields(ast, "ToBytes") {
+        Ok(fields) => fields,
+        Err(error) => return error.to_compile_error(),
+    };
+
+    let mut write_body = Vec::<TokenStream>::with_capacity(fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => quote! { #ident },
+            None => {
+                let index = Index::from(i);
+                quote! { #index }
+            }
+        };
+        match FieldKind::of(field) {
+            Ok(FieldKind::Plain) => {
+                write_body.push(quote! { snarkvm_utilities::ToBytes::write_le(&self.#member, &mut writer)?; })
+            }
+            Ok(FieldKind::Vec) => write_body.push(quote! {
+                let length = u32::try_from(self.#member.len())
+                    .map_err(|_| snarkvm_utilities::error("Vector length exceeds u32::MAX"))?;
+                snarkvm_utilities::ToBytes::write_le(&length, &mut writer)?;
+                for element in self.#member.iter() {
+                    snarkvm_utilities::ToBytes::write_le(element, &mut writer)?;
+                }
+            }),
+            Ok(FieldKind::Skip) => (),
+            Err(error) => return error.to_compile_error(),
+        }
+    }
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics snarkvm_utilities::ToBytes for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn write_le<W: snarkvm_utilities::io::Write>(&self, mut writer: W) -> snarkvm_utilities::io::Result<()> {
+                #(#write_body)*
+                Ok(())
+            }
+        }
+    }
+}
+
+pub(super) fn impl_from_bytes(ast: &DeriveInput) -> TokenStream {
+    let fields = match struct_fields(ast, "FromBytes") {
+        Ok(fields) => fields,
+        Err(error) => return error.to_compile_error(),
+    };
+
+    let mut read_body = Vec::<TokenStream>::with_capacity(fields.len());
+    let mut variables = Vec::<Ident>::with_capacity(fields.len());
+    for (i, field) in fields.iter().enumerate() {
+        let (variable, context) = match &field.ident {
+            Some(ident) => (ident.clone(), ident.to_string()),
+            None => (format_ident!("field_{}", i, span = Span::call_site()), i.to_string()),
+        };
+        let ty = &field.ty;
+        // Errors are annotated with the field name, and the element index for vectors.
+        match FieldKind::of(field) {
+            Ok(FieldKind::Plain) => read_body.push(quote! {
+                let #variable: #ty = snarkvm_utilities::IoResultExt::field_context(
+                    snarkvm_utilities::FromBytes::read_le(&mut reader),
+                    #context,
+                )?;
+            }),
+            Ok(FieldKind::Vec) => read_body.push(quote! {
+                let length: u32 = snarkvm_utilities::IoResultExt::field_context(
+                    snarkvm_utilities::FromBytes::read_le(&mut reader),
+                    #context,
+                )?;
+                let #variable: #ty = snarkvm_utilities::IoResultExt::field_context(
+                    (0..length as usize)
+                        .map(|index| {
+                            let element = snarkvm_utilities::FromBytes::read_le(&mut reader);
+                            snarkvm_utilities::IoResultExt::index_context(element, index)
+                        })
+                        .collect::<snarkvm_utilities::io::Result<#ty>>(),
+                    #context,
+                )?;
+            }),
+            Ok(FieldKind::Skip) => read_body.push(quote! { let #variable: #ty = Default::default(); }),
+            Err(error) => return error.to_compile_error(),
+        }
+        variables.push(variable);
+    }
+
+    let constructor = match fields {
+        Fields::Named(_) => quote! { Self { #(#variables),* } },
+        Fields::Unnamed(_) => quote! { Self(#(#variables),*) },
+        Fields::Unit => quote! { Self },
+    };
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics snarkvm_utilities::FromBytes for #name #ty_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn read_le<R: snarkvm_utilities::io::Read>(mut reader: R) -> snarkvm_utilities::io::Result<Self> {
+                #(#read_body)*
+                Ok(#constructor)
+            }
+        }
+    }
+}
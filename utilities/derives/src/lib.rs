@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod bytes;
+
 mod canonical_deserialize;
 
 mod canonical_serialize;
@@ -31,3 +33,15 @@ pub fn derive_canonical_deserialize(input: proc_macro::TokenStream) -> proc_macr
     let ast = parse_macro_input!(input as DeriveInput);
     proc_macro::TokenStream::from(canonical_deserialize::impl_canonical_deserialize(&ast))
 }
+
+#[proc_macro_derive(ToBytes, attributes(bytes))]
+pub fn derive_to_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(bytes::impl_to_bytes(&ast))
+}
+
+#[proc_macro_derive(FromBytes, attributes(bytes))]
+pub fn derive_from_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    proc_macro::TokenStream::from(bytes::impl_from_bytes(&ast))
+}
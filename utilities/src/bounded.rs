@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    error,
+    io::{Read, Result as IoResult},
+    FromBytes,
+    Vec,
+};
+
+/// The maximum number of bytes that are allocated for a vector before any of its elements are read,
+/// so that a forged length prefix cannot exhaust memory on a short input.
+pub const MAX_PREALLOCATION_SIZE_IN_BYTES: usize = 1 << 20;
+
+/// Returns the given `length` as a `usize`, or an error if it exceeds `max_length`.
+pub fn check_length_bounded<L: Into<u64>>(length: L, max_length: usize) -> IoResult<usize> {
+    let length = length.into();
+    match length <= max_length as u64 {
+        true => Ok(length as usize),
+        false => Err(error(format!("Length {length} exceeds the bound of {max_length}"))),
+    }
+}
+
+/// Returns the number of elements of type `T` to preallocate for a vector of the given `length`.
+pub(crate) fn preallocation_capacity<T>(length: usize) -> usize {
+    let max_num_elements = MAX_PREALLOCATION_SIZE_IN_BYTES / core::mem::size_of::<T>().max(1);
+    length.min(max_num_elements)
+}
+
+/// Reads exactly `length` elements from `reader`, allocating memory only as elements are read.
+///
+/// The caller is responsible for bounding `length`, e.g. with `check_length_bounded`.
+pub fn read_vec_exact<T: FromBytes, R: Read>(mut reader: R, length: usize) -> IoResult<Vec<T>> {
+    let mut elements = Vec::with_capacity(preallocation_capacity::<T>(length));
+    for _ in 0..length {
+        elements.push(T::read_le(&mut reader)?);
+    }
+    Ok(elements)
+}
+
+/// Reads a vector of at most `max_length` elements from `reader`, prefixed by its length as a `u32`.
+pub fn read_vec_bounded<T: FromBytes, R: Read>(mut reader: R, max_length: usize) -> IoResult<Vec<T>> {
+    let length = check_length_bounded(u32::read_le(&mut reader)?, max_length)?;
+    read_vec_exact(reader, length)
+}
+
+/// Reads exactly `length` bytes from `reader`, or an error if `length` exceeds `max_length`.
+///
+/// The buffer grows as bytes are read, so a forged `length` fails on a short input without a large allocation.
+pub fn read_exact_checked<R: Read>(mut reader: R, length: usize, max_length: usize) -> IoResult<Vec<u8>> {
+    let length = check_length_bounded(length as u64, max_length)?;
+    let mut bytes = Vec::with_capacity(preallocation_capacity::<u8>(length));
+    let mut chunk = [0u8; 1024];
+    while bytes.len() < length {
+        let chunk_length = chunk.len().min(length - bytes.len());
+        reader
+            .read_exact(&mut chunk[..chunk_length])
+            .map_err(|e| error(format!("Read {} of {length} bytes before failing - {e}", bytes.len())))?;
+        bytes.extend_from_slice(&chunk[..chunk_length]);
+    }
+    Ok(bytes)
+}
+
+/// A reader that fails once more than a given number of bytes have been read from it.
+pub struct BoundedReader<R: Read> {
+    /// The underlying reader.
+    reader: R,
+    /// The maximum number of bytes that may be read.
+    limit: usize,
+    /// The number of bytes that have been read so far.
+    num_bytes_read: usize,
+}
+
+impl<R: Read> BoundedReader<R> {
+    /// Initializes a new reader that reads at most `limit` bytes from `reader`.
+    pub const fn new(reader: R, limit: usize) -> Self {
+        Self { reader, limit, num_bytes_read: 0 }
+    }
+
+    /// Returns the number of bytes that have been read so far.
+    pub const fn num_bytes_read(&self) -> usize {
+        self.num_bytes_read
+    }
+
+    /// Returns the number of bytes that may still be read.
+    pub const fn remaining(&self) -> usize {
+        self.limit - self.num_bytes_read
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Returns an error if reading `num_bytes` more bytes would exceed the limit.
+    fn check_remaining(&self, num_bytes: usize) -> IoResult<()> {
+        match num_bytes <= self.remaining() {
+            true => Ok(()),
+            false => Err(error(format!(
+                "Reading {num_bytes} more bytes after {} exceeds the bound of {} bytes",
+                self.num_bytes_read, self.limit
+            ))),
+        }
+    }
+}
+
+impl<R: Read> Read for BoundedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        // Fail if the limit has been reached, so that a reader which never returns EOF is still cut off.
+        if self.remaining() == 0 {
+            self.check_remaining(buf.len())?;
+        }
+        let num_bytes = buf.len().min(self.remaining());
+        let num_bytes = self.reader.read(&mut buf[..num_bytes])?;
+        self.num_bytes_read += num_bytes;
+        Ok(num_bytes)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> IoResult<()> {
+        self.check_remaining(buf.len())?;
+        self.reader.read_exact(buf)?;
+        self.num_bytes_read += buf.len();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToBytes;
+
+    #[test]
+    fn test_check_length_bounded() {
+        assert_eq!(check_length_bounded(0u32, 0).unwrap(), 0);
+        assert_eq!(check_length_bounded(10u32, 10).unwrap(), 10);
+
+        let error = check_length_bounded(11u64, 10).unwrap_err();
+        assert_eq!(error.to_string(), "Length 11 exceeds the bound of 10");
+    }
+
+    #[test]
+    fn test_read_vec_bounded() {
+        let expected = vec![1u64, 2, 3, 4];
+        let mut bytes = (expected.len() as u32).to_bytes_le().unwrap();
+        bytes.extend(expected.to_bytes_le().unwrap());
+
+        assert_eq!(expected, read_vec_bounded::<u64, _>(&bytes[..], 4).unwrap());
+        assert!(read_vec_bounded::<u64, _>(&bytes[..], 3).is_err());
+        assert!(read_vec_bounded::<u64, _>(&bytes[..bytes.len() - 1], 4).is_err());
+    }
+
+    #[test]
+    fn test_read_exact_checked() {
+        let bytes = (0..=255u8).cycle().take(5000).collect::<Vec<_>>();
+
+        assert_eq!(bytes, read_exact_checked(&bytes[..], 5000, 5000).unwrap());
+        assert_eq!(bytes[..3000].to_vec(), read_exact_checked(&bytes[..], 3000, 5000).unwrap());
+        assert!(read_exact_checked(&bytes[..], 5000, 4999).is_err());
+
+        let error = read_exact_checked(&bytes[..], 6000, 10000).unwrap_err();
+        assert!(error.to_string().starts_with("Read 4096 of 6000 bytes before failing"));
+    }
+
+    #[test]
+    fn test_bounded_reader() {
+        let bytes = [7u8; 16];
+
+        let mut reader = BoundedReader::new(&bytes[..], 10);
+        assert_eq!(u64::read_le(&mut reader).unwrap(), u64::from_le_bytes([7u8; 8]));
+        assert_eq!(reader.num_bytes_read(), 8);
+        assert_eq!(reader.remaining(), 2);
+
+        let error = u32::read_le(&mut reader).unwrap_err();
+        assert_eq!(error.to_string(), "Reading 4 more bytes after 8 exceeds the bound of 10 bytes");
+        assert_eq!(u16::read_le(&mut reader).unwrap(), u16::from_le_bytes([7u8; 2]));
+        assert!(u8::read_le(&mut reader).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    error,
+    io::{self, Read, Result as IoResult},
+    CanonicalDeserialize,
+    CanonicalSerialize,
+    Compress,
+    FromBytes,
+    SerializationError,
+    ToBytes,
+    Valid,
+    Validate,
+    Vec,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Reads exactly `length` affine points, encoded with `ToBytes`, from `reader`.
+///
+/// Unlike `read_vec_exact`, the points are read from `reader` as one block, deserialized in parallel
+/// into a pre-sized vector, and, if `validate` is set, checked as a batch. This requires the encoding of
+/// every element to have the same size as the encoding of `T::default()`, as is the case for affine points.
+///
+/// The caller is responsible for bounding `length`, e.g. with `check_length_bounded`.
+pub fn read_affine_vec<T, R>(reader: R, length: usize, validate: Validate) -> IoResult<Vec<T>>
+where
+    T: FromBytes + ToBytes + Valid + Default + Clone + Send,
+    R: Read,
+{
+    let mut element = Vec::new();
+    T::default().write_le(&mut element)?;
+    read_fixed_size_vec(reader, length, element.len(), validate, |bytes| T::read_le(bytes))
+}
+
+/// Reads exactly `length` affine points, encoded with `CanonicalSerialize` in the given mode, from `reader`.
+///
+/// This is the equivalent of `read_affine_vec` for canonically-serialized points,
+/// where `Vec::<T>::deserialize_with_mode` would otherwise read them one at a time.
+///
+/// The caller is responsible for bounding `length`, e.g. with `check_length_bounded`.
+pub fn deserialize_affine_vec<T, R>(
+    reader: R,
+    length: usize,
+    compress: Compress,
+    validate: Validate,
+) -> Result<Vec<T>, SerializationError>
+where
+    T: CanonicalSerialize + CanonicalDeserialize + Default + Clone + Send,
+    R: Read,
+{
+    let element_size = T::default().serialized_size(compress);
+    Ok(read_fixed_size_vec(reader, length, element_size, validate, |bytes| {
+        Ok(T::deserialize_with_mode(bytes, compress, Validate::No)?)
+    })?)
+}
+
+/// Reads `length` elements of `element_size` bytes each from `reader` as one block,
+/// and deserializes them with `read_element`, in parallel when possible.
+fn read_fixed_size_vec<T, R, F>(
+    mut reader: R,
+    length: usize,
+    element_size: usize,
+    validate: Validate,
+    read_element: F,
+) -> IoResult<Vec<T>>
+where
+    T: Valid + Default + Clone + Send,
+    R: Read,
+    F: Fn(&[u8]) -> IoResult<T> + Sync,
+{
+    if element_size == 0 {
+        return Err(error("Cannot read a vector of zero-sized elements"));
+    }
+    let num_bytes = length
+        .checked_mul(element_size)
+        .ok_or_else(|| error(format!("Reading {length} elements of {element_size} bytes overflows")))?;
+
+    // Read the elements as one block.
+    let mut bytes = vec![0u8; num_bytes];
+    reader.read_exact(&mut bytes)?;
+
+    // Deserialize the elements into a pre-sized vector.
+    let mut elements = vec![T::default(); length];
+    #[cfg(feature = "parallel")]
+    let pairs = elements.par_iter_mut().zip(bytes.par_chunks(element_size));
+    #[cfg(not(feature = "parallel"))]
+    let mut pairs = elements.iter_mut().zip(bytes.chunks(element_size));
+    pairs.try_for_each(|(element, bytes)| {
+        *element = read_element(bytes)?;
+        Ok::<_, io::Error>(())
+    })?;
+
+    // Validate the elements as a batch.
+    if let Validate::Yes = validate {
+        T::batch_check(elements.iter())?;
+    }
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{read_vec_exact, rand::TestRng, Uniform};
+
+    #[test]
+    fn test_read_affine_vec() {
+        let rng = &mut TestRng::default();
+
+        for length in [0, 1, 10, 1000] {
+            let expected = (0..length).map(|_| u64::rand(rng)).collect::<Vec<_>>();
+
+            // Check the `ToBytes` encoding against the element-wise read.
+            let bytes = expected.iter().flat_map(|element| element.to_le_bytes()).collect::<Vec<_>>();
+            assert_eq!(expected, read_affine_vec::<u64, _>(&bytes[..], length, Validate::Yes).unwrap());
+            assert_eq!(expected, read_vec_exact::<u64, _>(&bytes[..], length).unwrap());
+
+            // Check the canonical encoding against the element-wise read.
+            let mut bytes = vec![];
+            expected.serialize_uncompressed(&mut bytes).unwrap();
+            let candidate = deserialize_affine_vec::<u64, _>(&bytes[8..], length, Compress::No, Validate::Yes).unwrap();
+            assert_eq!(expected, candidate);
+            assert_eq!(expected, Vec::<u64>::deserialize_uncompressed(&bytes[..]).unwrap());
+
+            // Ensure a truncated input is rejected.
+            if length > 0 {
+                assert!(read_affine_vec::<u64, _>(&bytes[8..bytes.len() - 1], length, Validate::Yes).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_affine_vec_overflowing_length() {
+        // Ensure a length whose size in bytes overflows is rejected, rather than allocated for.
+        let error = read_affine_vec::<u64, _>(&[0u8; 16][..], usize::MAX, Validate::No).unwrap_err();
+        assert_eq!(error.to_string(), format!("Reading {} elements of 8 bytes overflows", usize::MAX));
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    fmt,
+    io::{Error as IoError, Result as IoResult},
+    Error,
+    Vec,
+};
+
+/// A segment of the path to the value at which reading or writing bytes failed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Segment {
+    /// The name of a field.
+    Field(&'static str),
+    /// The index of an element in a sequence.
+    Index(usize),
+}
+
+/// An error from reading or writing bytes, annotated with the path to the value at which it occurred,
+/// e.g. `partial_solutions[713].commitment: could not deserialize KZGCommitment`.
+#[derive(Debug)]
+pub struct ContextError {
+    /// The segments of the path, from the innermost to the outermost.
+    segments: Vec<Segment>,
+    /// The underlying error.
+    source: IoError,
+}
+
+impl ContextError {
+    /// Returns the path to the value at which the error occurred.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in self.segments.iter().rev() {
+            match segment {
+                Segment::Field(field) if path.is_empty() => path.push_str(field),
+                Segment::Field(field) => {
+                    path.push('.');
+                    path.push_str(field);
+                }
+                Segment::Index(index) => path.push_str(&format!("[{index}]")),
+            }
+        }
+        path
+    }
+
+    /// Returns the underlying error.
+    pub const fn inner(&self) -> &IoError {
+        &self.source
+    }
+
+    /// Returns `error` with the given segment prepended to its path.
+    fn prepend(mut error: IoError, segment: Segment) -> IoError {
+        // If the error already has a path, extend it in place.
+        if let Some(context) = error.get_mut().and_then(|inner| inner.downcast_mut::<ContextError>()) {
+            context.segments.push(segment);
+            return error;
+        }
+        let kind = error.kind();
+        IoError::new(kind, ContextError { segments: vec![segment], source: error })
+    }
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path(), self.source)
+    }
+}
+
+impl Error for ContextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Annotates the errors from reading or writing bytes with the path to the value at which they occurred.
+///
+/// The annotations are only constructed on failure, so the happy path is unaffected.
+pub trait IoResultExt<T> {
+    /// Prepends the given field name to the path of the error, if any.
+    fn field_context(self, field: &'static str) -> IoResult<T>;
+
+    /// Prepends the given sequence index to the path of the error, if any.
+    fn index_context(self, index: usize) -> IoResult<T>;
+}
+
+impl<T> IoResultExt<T> for IoResult<T> {
+    #[inline]
+    fn field_context(self, field: &'static str) -> IoResult<T> {
+        self.map_err(|error| ContextError::prepend(error, Segment::Field(field)))
+    }
+
+    #[inline]
+    fn index_context(self, index: usize) -> IoResult<T> {
+        self.map_err(|error| ContextError::prepend(error, Segment::Index(index)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error, io::ErrorKind};
+
+    #[test]
+    fn test_context() {
+        let result: IoResult<()> = Err(error("invalid point encoding"));
+        let error = result.field_context("commitment").index_context(713).field_context("solutions").unwrap_err();
+
+        assert_eq!(error.to_string(), "solutions[713].commitment: invalid point encoding");
+        assert_eq!(error.kind(), ErrorKind::Other);
+
+        let context = error.get_ref().unwrap().downcast_ref::<ContextError>().unwrap();
+        assert_eq!(context.path(), "solutions[713].commitment");
+        assert_eq!(context.inner().to_string(), "invalid point encoding");
+    }
+
+    #[test]
+    fn test_context_preserves_kind() {
+        let result: IoResult<u8> = Err(ErrorKind::UnexpectedEof.into());
+        let error = result.index_context(0).field_context("elements").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+        assert!(error.to_string().starts_with("elements[0]: "));
+    }
+
+    #[test]
+    fn test_context_on_success() {
+        let result: IoResult<u8> = Ok(1);
+        assert_eq!(result.field_context("field").index_context(2).unwrap(), 1);
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FromBytes, ToBytes};
+
+use anyhow::{ensure, Result};
+use core::fmt;
+
+/// Encodes a value as the hex string of its little-endian bytes.
+///
+/// This is implemented for every type that implements `ToBytes`.
+pub trait ToHex: ToBytes + Sized {
+    /// Returns the hex encoding of `self`.
+    fn to_hex(&self) -> Result<String> {
+        Ok(hex::encode(self.to_bytes_le()?))
+    }
+
+    /// Returns the first `n` characters of the hex encoding of `self`, e.g. for logging.
+    fn to_short_hex(&self, n: usize) -> Result<String> {
+        let mut hex = self.to_hex()?;
+        hex.truncate(n);
+        Ok(hex)
+    }
+
+    /// Returns an adaptor that displays `self` in hex, e.g. `format!("{}", value.fmt_hex())`.
+    /// The precision truncates the output, e.g. `format!("{:.8}", value.fmt_hex())`.
+    fn fmt_hex(&self) -> HexDisplay<'_, Self> {
+        HexDisplay(self)
+    }
+}
+
+impl<T: ToBytes> ToHex for T {}
+
+/// Decodes a value from the hex string of its little-endian bytes.
+///
+/// This is implemented for every type that implements `FromBytes`.
+pub trait FromHex: FromBytes + Sized {
+    /// Returns `Self` from the given hex string, which must encode exactly one value.
+    fn from_hex(hex: &str) -> Result<Self> {
+        let bytes = hex::decode(hex)?;
+        let mut reader = &bytes[..];
+        let value = Self::read_le(&mut reader)?;
+        ensure!(reader.is_empty(), "Found {} trailing bytes after decoding from hex", reader.len());
+        Ok(value)
+    }
+}
+
+impl<T: FromBytes> FromHex for T {}
+
+/// Displays a value as the hex string of its little-endian bytes. See `ToHex::fmt_hex`.
+pub struct HexDisplay<'a, T: ToBytes>(&'a T);
+
+impl<T: ToBytes> fmt::Display for HexDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&self.0.to_hex().map_err(|_| fmt::Error)?)
+    }
+}
+
+impl<T: ToBytes> fmt::Debug for HexDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand::TestRng, Uniform};
+
+    #[test]
+    fn test_hex_round_trip() {
+        let rng = &mut TestRng::default();
+
+        for _ in 0..100 {
+            let expected = u128::rand(rng);
+            let hex = expected.to_hex().unwrap();
+            assert_eq!(hex, hex::encode(expected.to_le_bytes()));
+            assert_eq!(expected, u128::from_hex(&hex).unwrap());
+            assert_eq!(expected, u128::from_hex(&hex.to_uppercase()).unwrap());
+
+            // Check the short and formatted encodings.
+            assert_eq!(expected.to_short_hex(8).unwrap(), hex[..8]);
+            assert_eq!(expected.to_short_hex(64).unwrap(), hex);
+            assert_eq!(format!("{}", expected.fmt_hex()), hex);
+            assert_eq!(format!("{:.8}", expected.fmt_hex()), hex[..8]);
+        }
+    }
+
+    #[test]
+    fn test_from_hex_rejects_malformed_strings() {
+        // Odd-length strings.
+        assert!(u32::from_hex("0102030").is_err());
+        assert!(u32::from_hex("0").is_err());
+        // Invalid characters.
+        assert!(u32::from_hex("0102030g").is_err());
+        assert!(u32::from_hex("0x010203").is_err());
+        assert!(u32::from_hex(" 1020304").is_err());
+        // Too few or too many bytes.
+        assert!(u32::from_hex("").is_err());
+        assert!(u32::from_hex("010203").is_err());
+        assert!(u32::from_hex("0102030405").is_err());
+
+        assert_eq!(u32::from_hex("01020304").unwrap(), 0x04030201);
+    }
+}
@@ -43,6 +43,12 @@ pub use std::{boxed::Box, vec::Vec};
 #[macro_use]
 extern crate thiserror;
 
+// Re-exported for `assert_serde_consistency!`, so that the calling crate does not need to depend on them.
+#[doc(hidden)]
+pub use bincode;
+#[doc(hidden)]
+pub use serde_json;
+
 pub mod biginteger;
 pub use biginteger::*;
 
@@ -53,13 +59,25 @@ pub use bititerator::*;
 pub mod bits;
 pub use bits::*;
 
+pub mod bounded;
+pub use bounded::*;
+
+pub mod bulk;
+pub use bulk::*;
+
 #[macro_use]
 pub mod bytes;
 pub use bytes::*;
 
+pub mod context;
+pub use context::*;
+
 pub mod error;
 pub use error::*;
 
+pub mod hex_ext;
+pub use hex_ext::*;
+
 pub mod iterator;
 pub use iterator::*;
 
@@ -70,6 +88,10 @@ pub use parallel::*;
 pub mod rand;
 pub use self::rand::*;
 
+#[macro_use]
+pub mod serde_ext;
+pub use serde_ext::*;
+
 pub mod serialize;
 pub use serialize::*;
 
@@ -90,10 +90,46 @@ pub fn execute_with_max_available_threads<T>(f: impl FnOnce() -> T + Send) -> T
 #[cfg(feature = "parallel")]
 #[inline(always)]
 fn execute_with_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send, num_threads: usize) -> T {
+    // Within a scoped pool, stay on it rather than spawning a fresh pool.
+    if is_in_scoped_pool() {
+        return f();
+    }
     let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
     pool.install(f)
 }
 
+#[cfg(feature = "parallel")]
+thread_local! {
+    /// The number of active `execute_with_pool` scopes covering the current worker thread.
+    static SCOPED_POOL_DEPTH: crate::cell::Cell<usize> = const { crate::cell::Cell::new(0) };
+}
+
+/// Returns `true` if the current thread belongs to a pool installed via `execute_with_pool`.
+#[cfg(feature = "parallel")]
+fn is_in_scoped_pool() -> bool {
+    SCOPED_POOL_DEPTH.with(|depth| depth.get() > 0)
+}
+
+/// Executes `f` on the given thread pool, instead of rayon's global pool.
+///
+/// All `cfg_*` parallel iterators and `execute_with_max_available_threads` calls made within `f`
+/// are confined to the threads of `pool`. Outside of this function, the global pool is used as before.
+#[cfg(feature = "parallel")]
+pub fn execute_with_pool<T: Send>(pool: &rayon::ThreadPool, f: impl FnOnce() -> T + Send) -> T {
+    /// Leaves the scope on every thread of the pool, even if `f` panics.
+    struct ScopeGuard<'a>(&'a rayon::ThreadPool);
+
+    impl Drop for ScopeGuard<'_> {
+        fn drop(&mut self) {
+            self.0.broadcast(|_| SCOPED_POOL_DEPTH.with(|depth| depth.set(depth.get() - 1)));
+        }
+    }
+
+    pool.broadcast(|_| SCOPED_POOL_DEPTH.with(|depth| depth.set(depth.get() + 1)));
+    let _guard = ScopeGuard(pool);
+    pool.install(f)
+}
+
 /// Creates parallel iterator over refs if `parallel` feature is enabled.
 #[macro_export]
 macro_rules! cfg_iter {
@@ -178,3 +214,58 @@ macro_rules! cfg_reduce {
         result
     }};
 }
+
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use super::*;
+    use rayon::prelude::*;
+
+    use std::collections::HashSet;
+
+    const POOL_PREFIX: &str = "scoped-pool-";
+
+    fn thread_names(num_items: usize) -> HashSet<Option<String>> {
+        let items = (0..num_items).collect::<Vec<_>>();
+        cfg_iter!(items).map(|_| std::thread::current().name().map(str::to_string)).collect()
+    }
+
+    fn scoped_pool() -> rayon::ThreadPool {
+        rayon::ThreadPoolBuilder::new().num_threads(2).thread_name(|i| format!("{POOL_PREFIX}{i}")).build().unwrap()
+    }
+
+    fn is_scoped(name: &Option<String>) -> bool {
+        name.as_deref().map_or(false, |name| name.starts_with(POOL_PREFIX))
+    }
+
+    #[test]
+    fn test_execute_with_pool() {
+        let pool = scoped_pool();
+
+        // Parallel iterators run on the scoped pool.
+        let names = execute_with_pool(&pool, || thread_names(1000));
+        assert!(!names.is_empty());
+        assert!(names.iter().all(is_scoped));
+
+        // Nested calls to `execute_with_max_available_threads` remain on the scoped pool.
+        let names = execute_with_pool(&pool, || execute_with_max_available_threads(|| thread_names(1000)));
+        assert!(names.iter().all(is_scoped));
+
+        // The default behavior is unchanged.
+        let names = thread_names(1000);
+        assert!(!names.iter().any(is_scoped));
+        let names = execute_with_max_available_threads(|| thread_names(1000));
+        assert!(!names.iter().any(is_scoped));
+    }
+
+    #[test]
+    fn test_execute_with_pool_leaves_scope_on_panic() {
+        let pool = scoped_pool();
+
+        assert!(execute_with_pool(&pool, is_in_scoped_pool));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| execute_with_pool(&pool, || panic!())));
+        assert!(result.is_err());
+
+        // Ensure the threads of the pool are no longer marked as scoped.
+        assert!(pool.broadcast(|_| !is_in_scoped_pool()).into_iter().all(|is_unscoped| is_unscoped));
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{FromBytes, FromBytesDeserializer, FromHex, ToBytes, ToBytesSerializer, ToHex};
+
+use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+/// Serializes `object` as a hex string if `serializer` is human-readable, and as length-prefixed bytes otherwise.
+pub fn serialize_hex_or_bytes<T: ToBytes, S: Serializer>(object: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    match serializer.is_human_readable() {
+        true => serializer.serialize_str(&object.to_hex().map_err(ser::Error::custom)?),
+        false => ToBytesSerializer::serialize_with_size_encoding(object, serializer),
+    }
+}
+
+/// Deserializes a `T` that was serialized with `serialize_hex_or_bytes`.
+pub fn deserialize_hex_or_bytes<'de, T: FromBytes, D: Deserializer<'de>>(
+    deserializer: D,
+    name: &str,
+) -> Result<T, D::Error> {
+    match deserializer.is_human_readable() {
+        true => T::from_hex(&String::deserialize(deserializer)?).map_err(de::Error::custom),
+        false => FromBytesDeserializer::<T>::deserialize_with_size_encoding(deserializer, name),
+    }
+}
+
+/// Serializes and deserializes a value as a hex string in human-readable formats, and as bytes otherwise.
+///
+/// This is implemented for every type that implements `ToBytes` and `FromBytes`, so that
+/// `Serialize` and `Deserialize` can be implemented as one-line calls to these methods.
+pub trait SerializeExt: ToBytes + FromBytes + Sized {
+    /// Serializes `self` with `serialize_hex_or_bytes`.
+    fn serialize_hex_or_bytes<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_hex_or_bytes(self, serializer)
+    }
+
+    /// Deserializes `Self` with `deserialize_hex_or_bytes`.
+    fn deserialize_hex_or_bytes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_hex_or_bytes(deserializer, core::any::type_name::<Self>())
+    }
+}
+
+impl<T: ToBytes + FromBytes> SerializeExt for T {}
+
+/// Asserts that a value has a consistent serde representation, by checking that:
+///  - it round-trips through JSON,
+///  - it round-trips through bincode,
+///  - the JSON of its bincode round-trip matches its JSON, and deserializes back to it,
+///  - the bincode of its JSON round-trip matches its bincode.
+#[macro_export]
+macro_rules! assert_serde_consistency {
+    ($type:ty, $value:expr) => {{
+        let expected: $type = $value;
+
+        // Check the JSON round trip.
+        let json = $crate::serde_json::to_string(&expected).unwrap();
+        let from_json: $type = $crate::serde_json::from_str(&json).unwrap();
+        assert_eq!(expected, from_json, "JSON round trip of {} changed the value", stringify!($type));
+
+        // Check the bincode round trip.
+        let bytes = $crate::bincode::serialize(&expected).unwrap();
+        let from_bincode: $type = $crate::bincode::deserialize(&bytes).unwrap();
+        assert_eq!(expected, from_bincode, "bincode round trip of {} changed the value", stringify!($type));
+
+        // Check the representations are consistent across the round trips.
+        let json_from_bincode = $crate::serde_json::to_string(&from_bincode).unwrap();
+        assert_eq!(json, json_from_bincode, "JSON of {} changed after a bincode round trip", stringify!($type));
+        let from_json_from_bincode: $type = $crate::serde_json::from_str(&json_from_bincode).unwrap();
+        assert_eq!(expected, from_json_from_bincode, "JSON of {} from bincode is inconsistent", stringify!($type));
+        let bytes_from_json = $crate::bincode::serialize(&from_json).unwrap();
+        assert_eq!(bytes, bytes_from_json, "bincode of {} changed after a JSON round trip", stringify!($type));
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Pair(u32, [u8; 4]);
+
+    impl ToBytes for Pair {
+        fn write_le<W: crate::io::Write>(&self, mut writer: W) -> crate::io::Result<()> {
+            self.0.write_le(&mut writer)?;
+            self.1.write_le(&mut writer)
+        }
+    }
+
+    impl FromBytes for Pair {
+        fn read_le<R: crate::io::Read>(mut reader: R) -> crate::io::Result<Self> {
+            Ok(Self(FromBytes::read_le(&mut reader)?, FromBytes::read_le(&mut reader)?))
+        }
+    }
+
+    impl Serialize for Pair {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.serialize_hex_or_bytes(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Pair {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Self::deserialize_hex_or_bytes(deserializer)
+        }
+    }
+
+    #[test]
+    fn test_hex_or_bytes() {
+        let expected = Pair(0x04030201, [5, 6, 7, 8]);
+
+        // The human-readable representation is a hex string.
+        let json = serde_json::to_string(&expected).unwrap();
+        assert_eq!(json, "\"0102030405060708\"");
+        assert_eq!(expected, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<Pair>("\"010203040506070\"").is_err());
+        assert!(serde_json::from_str::<Pair>("\"01020304050607\"").is_err());
+
+        // The binary representation is the length-prefixed bytes.
+        let bytes = bincode::serialize(&expected).unwrap();
+        assert_eq!(&bytes[..8], &8u64.to_le_bytes());
+        assert_eq!(&bytes[8..], &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(expected, bincode::deserialize(&bytes).unwrap());
+
+        assert_serde_consistency!(Pair, expected);
+    }
+}
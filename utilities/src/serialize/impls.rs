@@ -163,7 +163,7 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
     #[inline]
     fn serialized_size(&self, compress: Compress) -> usize {
-        8 + self.as_ref().map(|s| s.serialized_size(compress)).unwrap_or(0)
+        self.is_some().serialized_size(compress) + self.as_ref().map(|s| s.serialized_size(compress)).unwrap_or(0)
     }
 }
 
@@ -595,6 +595,15 @@ mod test {
     fn test_option() {
         test_serialize(Some(3u32));
         test_serialize(None::<u32>);
+
+        // The presence of a value is written as a one-byte `bool`.
+        for compress in [Compress::No, Compress::Yes] {
+            let mut serialized = vec![];
+            Some(3u32).serialize_with_mode(&mut serialized, compress).unwrap();
+            assert_eq!(serialized.len(), 5);
+            assert_eq!(Some(3u32).serialized_size(compress), 5);
+            assert_eq!(None::<u32>.serialized_size(compress), 1);
+        }
     }
 
     #[test]
@@ -62,13 +62,13 @@ pub trait Flags: Default + Clone + Copy + Sized {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Compress {
     Yes,
     No,
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Validate {
     Yes,
     No,
@@ -174,6 +174,27 @@ pub trait CanonicalDeserialize: Valid {
     }
 }
 
+/// Reads and writes a canonically-serializable type as little-endian bytes, in an explicit mode.
+///
+/// Implementors declare the modes of their `ToBytes` and `FromBytes` implementations in `COMPRESS`
+/// and `VALIDATE`, so that callers may write or read the other encoding without guessing the default.
+pub trait CanonicalBytes: CanonicalSerialize + CanonicalDeserialize {
+    /// The compression mode used by `ToBytes::write_le` and `FromBytes::read_le`.
+    const COMPRESS: Compress;
+    /// The validation mode used by `FromBytes::read_le`.
+    const VALIDATE: Validate = Validate::Yes;
+
+    /// Writes `self` into `writer` with the given compression mode.
+    fn write_le_with<W: Write>(&self, writer: W, compress: Compress) -> io::Result<()> {
+        Ok(self.serialize_with_mode(writer, compress)?)
+    }
+
+    /// Reads `Self` from `reader` with the given compression and validation modes.
+    fn read_le_with<R: Read>(reader: R, compress: Compress, validate: Validate) -> io::Result<Self> {
+        Ok(Self::deserialize_with_mode(reader, compress, validate)?)
+    }
+}
+
 /// Serializer in little endian format allowing to encode flags.
 pub trait CanonicalSerializeWithFlags: CanonicalSerialize {
     /// Serializes `self` and `flags` into `writer`.
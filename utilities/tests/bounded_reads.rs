@@ -0,0 +1,107 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_utilities::{read_exact_checked, read_vec_bounded, BoundedReader, ToBytes, MAX_PREALLOCATION_SIZE_IN_BYTES};
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// An allocator that records the largest allocation made by each thread.
+struct CountingAllocator;
+
+thread_local! {
+    static LARGEST_ALLOCATION: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = LARGEST_ALLOCATION.try_with(|largest| largest.set(largest.get().max(layout.size())));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = LARGEST_ALLOCATION.try_with(|largest| largest.set(largest.get().max(new_size)));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `f`, and returns the size of the largest allocation it made.
+fn largest_allocation(f: impl FnOnce()) -> usize {
+    LARGEST_ALLOCATION.with(|largest| largest.set(0));
+    f();
+    LARGEST_ALLOCATION.with(|largest| largest.get())
+}
+
+/// Returns a short input that claims to contain `length` elements.
+fn length_bomb(length: u32) -> Vec<u8> {
+    let mut bytes = length.to_bytes_le().unwrap();
+    bytes.extend([1u8; 64]);
+    bytes
+}
+
+#[test]
+fn test_read_vec_bounded_rejects_length_over_bound() {
+    let bytes = length_bomb(u32::MAX);
+    let allocated = largest_allocation(|| {
+        let error = read_vec_bounded::<u64, _>(&bytes[..], 1 << 20).unwrap_err();
+        assert_eq!(error.to_string(), format!("Length {} exceeds the bound of {}", u32::MAX, 1 << 20));
+    });
+    assert!(allocated < 1024, "Allocated {allocated} bytes");
+}
+
+#[test]
+fn test_read_vec_bounded_fails_fast_within_bound() {
+    // Even when the claimed length is within the bound, the input runs out long before the claimed length.
+    for length in [1 << 10, 1 << 20, u32::MAX] {
+        let bytes = length_bomb(length);
+        let allocated = largest_allocation(|| {
+            assert!(read_vec_bounded::<u64, _>(&bytes[..], u32::MAX as usize).is_err());
+            assert!(read_vec_bounded::<[u8; 1024], _>(&bytes[..], u32::MAX as usize).is_err());
+        });
+        assert!(allocated <= MAX_PREALLOCATION_SIZE_IN_BYTES + 1024, "Allocated {allocated} bytes");
+    }
+}
+
+#[test]
+fn test_read_exact_checked_fails_fast() {
+    let bytes = [1u8; 64];
+    let allocated = largest_allocation(|| {
+        assert!(read_exact_checked(&bytes[..], usize::MAX, usize::MAX).is_err());
+        assert!(read_exact_checked(&bytes[..], 1 << 40, 1 << 30).is_err());
+    });
+    assert!(allocated <= MAX_PREALLOCATION_SIZE_IN_BYTES + 1024, "Allocated {allocated} bytes");
+}
+
+#[test]
+fn test_bounded_reader_stops_length_bomb() {
+    // An endless input is cut off by the bounded reader.
+    let endless = std::io::repeat(1u8);
+    let allocated = largest_allocation(|| {
+        let reader = BoundedReader::new(endless, 1 << 16);
+        let error = read_vec_bounded::<u64, _>(reader, u32::MAX as usize).unwrap_err();
+        assert_eq!(error.to_string(), "Reading 8 more bytes after 65532 exceeds the bound of 65536 bytes");
+    });
+    assert!(allocated <= MAX_PREALLOCATION_SIZE_IN_BYTES + 1024, "Allocated {allocated} bytes");
+}
@@ -0,0 +1,165 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+struct Named {
+    a: u8,
+    b: u64,
+    c: [u16; 2],
+}
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+struct Unnamed(u32, Named);
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+struct Unit;
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+struct WithVec {
+    header: u16,
+    elements: Vec<Named>,
+    footer: u8,
+}
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+struct WithSkip {
+    a: u32,
+    #[bytes(skip)]
+    cache: Option<u64>,
+    b: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, ToBytes, FromBytes)]
+struct Generic<T: ToBytes + FromBytes> {
+    value: T,
+    values: Vec<T>,
+}
+
+fn named(i: u8) -> Named {
+    Named { a: i, b: u64::MAX - i as u64, c: [i as u16, 0xABCD] }
+}
+
+#[test]
+fn test_named() {
+    let expected = named(7);
+    let bytes = expected.to_bytes_le().unwrap();
+
+    // The fields are written in declaration order, without any framing.
+    let mut expected_bytes = vec![7u8];
+    expected_bytes.extend_from_slice(&(u64::MAX - 7).to_le_bytes());
+    expected_bytes.extend_from_slice(&7u16.to_le_bytes());
+    expected_bytes.extend_from_slice(&0xABCDu16.to_le_bytes());
+    assert_eq!(expected_bytes, bytes);
+
+    assert_eq!(expected, Named::read_le(&bytes[..]).unwrap());
+    assert!(Named::read_le(&bytes[1..]).is_err());
+}
+
+#[test]
+fn test_unnamed() {
+    let expected = Unnamed(0xDEADBEEF, named(3));
+    let bytes = expected.to_bytes_le().unwrap();
+
+    let mut expected_bytes = 0xDEADBEEFu32.to_le_bytes().to_vec();
+    expected_bytes.extend(named(3).to_bytes_le().unwrap());
+    assert_eq!(expected_bytes, bytes);
+
+    assert_eq!(expected, Unnamed::read_le(&bytes[..]).unwrap());
+}
+
+#[test]
+fn test_unit() {
+    let bytes = Unit.to_bytes_le().unwrap();
+    assert!(bytes.is_empty());
+    assert_eq!(Unit, Unit::read_le(&bytes[..]).unwrap());
+}
+
+#[test]
+fn test_vec() {
+    for num_elements in [0u8, 1, 5] {
+        let expected = WithVec { header: 0x1234, elements: (0..num_elements).map(named).collect(), footer: 0xFF };
+        let bytes = expected.to_bytes_le().unwrap();
+
+        // The vector is prefixed with its length as a u32.
+        let mut expected_bytes = 0x1234u16.to_le_bytes().to_vec();
+        expected_bytes.extend_from_slice(&(num_elements as u32).to_le_bytes());
+        for element in &expected.elements {
+            expected_bytes.extend(element.to_bytes_le().unwrap());
+        }
+        expected_bytes.push(0xFF);
+        assert_eq!(expected_bytes, bytes);
+
+        assert_eq!(expected, WithVec::read_le(&bytes[..]).unwrap());
+        assert!(WithVec::read_le(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
+
+#[test]
+fn test_vec_with_invalid_length() {
+    // A length prefix that exceeds the remaining bytes is rejected, without allocating up front.
+    let mut bytes = 0u16.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+    bytes.extend(named(1).to_bytes_le().unwrap());
+    assert!(WithVec::read_le(&bytes[..]).is_err());
+}
+
+#[test]
+fn test_error_context() {
+    let value = WithVec { header: 1, elements: vec![named(1), named(2)], footer: 2 };
+    let bytes = value.to_bytes_le().unwrap();
+
+    // Truncate the input within the `b` field of the second element.
+    let error = WithVec::read_le(&bytes[..2 + 4 + 13 + 5]).unwrap_err();
+    assert!(error.to_string().starts_with("elements[1].b: "), "{error}");
+
+    // Truncate the input within the length prefix.
+    let error = WithVec::read_le(&bytes[..4]).unwrap_err();
+    assert!(error.to_string().starts_with("elements: "), "{error}");
+
+    // Truncate the input within a nested unnamed field.
+    let bytes = Unnamed(1, named(1)).to_bytes_le().unwrap();
+    let error = Unnamed::read_le(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert!(error.to_string().starts_with("1.c: "), "{error}");
+}
+
+#[test]
+fn test_skip() {
+    let value = WithSkip { a: 1, cache: Some(2), b: 3 };
+    let bytes = value.to_bytes_le().unwrap();
+
+    let mut expected_bytes = 1u32.to_le_bytes().to_vec();
+    expected_bytes.extend_from_slice(&3u32.to_le_bytes());
+    assert_eq!(expected_bytes, bytes);
+
+    // The skipped field is restored with its default value.
+    assert_eq!(WithSkip { a: 1, cache: None, b: 3 }, WithSkip::read_le(&bytes[..]).unwrap());
+}
+
+#[test]
+fn test_generic() {
+    let expected = Generic { value: 1u64, values: vec![2u64, 3, 4] };
+    let bytes = expected.to_bytes_le().unwrap();
+    assert_eq!(8 + 4 + 3 * 8, bytes.len());
+    assert_eq!(expected, Generic::<u64>::read_le(&bytes[..]).unwrap());
+}
+
+#[test]
+fn test_unsupported_shapes() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,11 @@
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+#[derive(ToBytes, FromBytes)]
+enum Enum {
+    A(u8),
+    B(u16),
+}
+
+fn main() {
+    let _ = (Enum::A(0), Enum::B(0));
+}
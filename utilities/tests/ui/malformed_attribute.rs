@@ -0,0 +1,11 @@
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+#[derive(ToBytes, FromBytes)]
+struct Struct {
+    #[bytes = "skip"]
+    a: u8,
+}
+
+fn main() {
+    let _ = Struct { a: 0 }.a;
+}